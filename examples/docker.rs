@@ -297,7 +297,10 @@ fn main() -> Result<(), Error> {
         Err(Error::Help(Some(value))
             | Error::Version(Some(value))
             | Error::License(Some(value))
-            | Error::Author(Some(value))) => return Ok(println!("{}", value)),
+            | Error::Author(Some(value))) => {
+            println!("{}", value);
+            return Ok(());
+        }
         Err(error) => return Err(error),
     };
     assert_eq!(docker.global.config, "boba".to_string());
@@ -3,17 +3,26 @@ use regex::RegexSet;
 use crate::{
     case::Case,
     error::Error,
-    meta::{Meta, Name, Options},
+    locale::{self, English, Locale},
+    meta::{Builtin, Meta, Name, Options},
     parse::{
-        Any, At, Default, Environment, Indices, Many, Map, Node, Parse, Parser, Require, Value,
-        With,
+        Any, At, Complete, Default, Environment, Indices, Many, Map, Node, Parse, Parser, Prompt,
+        Require, Slot, Source, Value, With,
     },
     scope::{self, Scope},
     stack::Stack,
-    style, AUTHOR, BREAK, HELP, LICENSE, MAXIMUM, SHIFT, VERSION,
+    style::{self, ColorChoice},
+    AUTHOR, BREAK, BRIEF, COLOR, HELP, LICENSE, MAXIMUM, SEARCH, SHIFT, VERBOSE, VERSION,
 };
 use core::{any::TypeId, default, fmt, marker::PhantomData, num::NonZeroUsize, str::FromStr};
-use std::{any, borrow::Cow, collections::hash_map::Entry, convert::Infallible, mem::replace};
+use std::{
+    any,
+    borrow::Cow,
+    collections::hash_map::Entry,
+    convert::Infallible,
+    env,
+    mem::{replace, take},
+};
 
 pub struct Builder<S, P = At<()>> {
     case: Case,
@@ -24,6 +33,12 @@ pub struct Builder<S, P = At<()>> {
     parse: Result<P, Error>,
     scope: S,
     style: Box<dyn style::Style>,
+    color: ColorChoice,
+    width: (usize, usize),
+    suggestions: (usize, usize),
+    pager: bool,
+    markup: bool,
+    locale: Box<dyn Locale>,
     position: usize,
 }
 
@@ -129,6 +144,12 @@ impl<S, P> Builder<S, P> {
             long: self.long,
             buffer: self.buffer,
             style: self.style,
+            color: self.color,
+            width: self.width,
+            suggestions: self.suggestions,
+            pager: self.pager,
+            markup: self.markup,
+            locale: self.locale,
             scope: scope(self.scope),
             parse: self.parse.and_then(parse),
             position: self.position,
@@ -145,6 +166,12 @@ impl<S, P> Builder<S, P> {
                 long: self.long,
                 buffer: self.buffer,
                 style: self.style,
+                color: self.color,
+                width: self.width,
+                suggestions: self.suggestions,
+                pager: self.pager,
+                markup: self.markup,
+                locale: self.locale,
                 scope,
                 parse: self.parse,
                 position: self.position,
@@ -163,6 +190,12 @@ impl<S, P> Builder<S, P> {
                 long: self.long,
                 buffer: self.buffer,
                 style: self.style,
+                color: self.color,
+                width: self.width,
+                suggestions: self.suggestions,
+                pager: self.pager,
+                markup: self.markup,
+                locale: self.locale,
                 scope,
                 parse: Ok(parse),
                 position: self.position,
@@ -183,11 +216,14 @@ impl<S, P> Builder<S, P> {
                 options,
             )?;
             if options {
+                let custom = Self::custom(metas);
                 if let Some(true) = version {
-                    metas.extend(self.insert_version(&mut indices, true, true)?);
+                    let option = Options::version(true, true);
+                    metas.extend(self.insert_options(&mut indices, option, &custom)?);
                 }
                 if let Some(true) = help {
-                    metas.extend(self.insert_help(&mut indices, true, true)?);
+                    let option = Options::help(true, true);
+                    metas.extend(self.insert_options(&mut indices, option, &custom)?);
                 }
                 if version.is_some() || help.is_some() {
                     Self::insert_key(self.long.clone(), &mut indices, BREAK)?;
@@ -209,6 +245,7 @@ impl<S, P> Builder<S, P> {
         let mut index = 0;
         let mut version = None;
         let mut help = None;
+        let custom = Self::custom(metas);
         for i in 0..metas.len() {
             let value = (index << shift) | mask;
             let Some(meta) = metas.get_mut(i) else {
@@ -217,7 +254,7 @@ impl<S, P> Builder<S, P> {
             match meta {
                 Meta::Version(_) if *show => version = version.or(Some(true)),
                 Meta::Valid(value) => patterns.push(format!("^{value}$")),
-                Meta::Help(_) | Meta::Usage(_) | Meta::Note(_) if *show => {
+                Meta::Help(_) | Meta::Details(_) | Meta::Usage(_) | Meta::Note(_) if *show => {
                     help = help.or(Some(true))
                 }
                 Meta::Hide => *show = false,
@@ -252,18 +289,7 @@ impl<S, P> Builder<S, P> {
                     index += 1;
                 }
                 Meta::Options(option) if options => {
-                    let option = match *option {
-                        Options::Help { short, long } => self.insert_help(indices, short, long)?,
-                        Options::Version { short, long } => {
-                            self.insert_version(indices, short, long)?
-                        }
-                        Options::License { short, long } => {
-                            self.insert_license(indices, short, long)?
-                        }
-                        Options::Author { short, long } => {
-                            self.insert_author(indices, short, long)?
-                        }
-                    };
+                    let option = self.insert_options(indices, *option, &custom)?;
                     if let Some(option) = option {
                         *meta = option;
                     }
@@ -284,6 +310,9 @@ impl<S, P> Builder<S, P> {
                     Self::insert_key(value.clone(), indices, index)?;
                     has = true;
                 }
+                Some(Meta::Weight(weight)) => {
+                    indices.weights.insert(index, *weight);
+                }
                 None => break,
                 _ => {}
             };
@@ -314,6 +343,9 @@ impl<S, P> Builder<S, P> {
                     }
                 }
                 Some(Meta::Swizzle) => swizzle = true,
+                Some(Meta::Weight(weight)) => {
+                    indices.weights.insert(index, *weight);
+                }
                 Some(Meta::Position(_)) => {
                     indices.positions.push(index);
                     has = true;
@@ -336,91 +368,100 @@ impl<S, P> Builder<S, P> {
         }
     }
 
-    fn insert_version(
-        &mut self,
-        indices: &mut Indices,
-        short: bool,
-        long: bool,
-    ) -> Result<Option<Meta>, Error> {
-        self.insert_option(
-            indices,
-            if short { Some("v") } else { None },
-            if long { Some("version") } else { None },
-            "Displays version information.",
-            VERSION,
-        )
-    }
-
-    fn insert_license(
-        &mut self,
-        indices: &mut Indices,
-        short: bool,
-        long: bool,
-    ) -> Result<Option<Meta>, Error> {
-        self.insert_option(
-            indices,
-            if short { Some("l") } else { None },
-            if long { Some("license") } else { None },
-            "Displays license information.",
-            LICENSE,
-        )
-    }
-
-    fn insert_author(
-        &mut self,
-        indices: &mut Indices,
-        short: bool,
-        long: bool,
-    ) -> Result<Option<Meta>, Error> {
-        self.insert_option(
-            indices,
-            if short { Some("a") } else { None },
-            if long { Some("author") } else { None },
-            "Displays author information.",
-            AUTHOR,
-        )
-    }
-
-    fn insert_help(
-        &mut self,
-        indices: &mut Indices,
-        short: bool,
-        long: bool,
-    ) -> Result<Option<Meta>, Error> {
-        self.insert_option(
-            indices,
-            if short { Some("h") } else { None },
-            if long { Some("help") } else { None },
-            "Displays this help message.",
-            HELP,
-        )
+    fn custom(metas: &[Meta]) -> Vec<(Builtin, Vec<Meta>)> {
+        metas
+            .iter()
+            .filter_map(|meta| match meta {
+                Meta::Builtin(builtin, metas) => {
+                    Some((*builtin, metas.iter().map(|meta| meta.clone(0)).collect()))
+                }
+                _ => None,
+            })
+            .collect()
     }
 
-    fn insert_option(
+    fn insert_options(
         &mut self,
         indices: &mut Indices,
-        short: Option<&'static str>,
-        long: Option<&'static str>,
-        help: &'static str,
-        index: usize,
+        options: Options,
+        custom: &[(Builtin, Vec<Meta>)],
     ) -> Result<Option<Meta>, Error> {
-        let mut option = vec![Meta::Help(Cow::Borrowed(help))];
-        if let Some(short) = short {
-            let (name, value) = self.option_name(short)?;
-            if Self::insert_key(value.clone(), indices, index).is_ok() {
-                option.push(Meta::Name(name, value));
+        let builtin = options.builtin();
+        let index = match builtin {
+            Builtin::Author => AUTHOR,
+            Builtin::License => LICENSE,
+            Builtin::Version => VERSION,
+            Builtin::Verbose => VERBOSE,
+            Builtin::Help => HELP,
+            Builtin::Search => SEARCH,
+            Builtin::Color => COLOR,
+        };
+        let custom = custom
+            .iter()
+            .filter(|pair| pair.0 == builtin)
+            .flat_map(|pair| &pair.1);
+        let mut help = None;
+        let mut details = Vec::new();
+        let mut hidden = false;
+        let mut weight = None;
+        let mut names = [Vec::new(), Vec::new()];
+        for meta in custom {
+            match meta {
+                Meta::Help(value) => help = Some(value.clone()),
+                Meta::Details(value) => details.push(Meta::Details(value.clone())),
+                Meta::Hide => hidden = true,
+                Meta::Show => hidden = false,
+                Meta::Weight(value) => weight = Some(*value),
+                Meta::Name(Name::Short, value) => names[0].push((Name::Short, value.clone())),
+                Meta::Name(name, value) => names[1].push((*name, value.clone())),
+                _ => return Err(Error::InvalidBuiltinOption(builtin)),
             }
         }
-        if let Some(long) = long {
-            let (name, value) = self.option_name(long)?;
-            if Self::insert_key(value.clone(), indices, index).is_ok() {
-                option.push(Meta::Name(name, value));
+        let help = help.unwrap_or_else(|| self.locale.help(builtin));
+        let mut option = vec![Meta::Help(help)];
+        option.extend(details);
+        let length = option.len();
+        for ((short, default), mut names) in
+            [true, false].into_iter().zip(options.names()).zip(names)
+        {
+            match default {
+                Some(default) if names.is_empty() => names.push(self.option_name(default)?),
+                _ => {}
+            }
+            let index = match builtin {
+                Builtin::Help if short => BRIEF,
+                _ => index,
+            };
+            if let (Some(weight), false) = (weight, names.is_empty()) {
+                indices.weights.insert(index, weight);
+            }
+            for (name, value) in names {
+                if Self::insert_key(value.clone(), indices, index).is_ok() {
+                    if let (Builtin::Version | Builtin::Verbose, Name::Short) = (builtin, name) {
+                        indices.swizzles.extend(value.chars().nth(self.short.len()));
+                    }
+                    option.push(Meta::Name(name, value));
+                }
             }
         }
-        if option.len() > 1 {
-            Ok(Some(Meta::Option(option)))
+        if option.len() == length {
+            return Ok(None);
+        }
+        match builtin {
+            Builtin::Search => {
+                option.push(Meta::Type(Cow::Borrowed("string")));
+                option.push(Meta::Value(Cow::Borrowed("keyword")));
+            }
+            Builtin::Color => {
+                option.push(Meta::Type(Cow::Borrowed("color")));
+                option.push(Meta::Value(Cow::Borrowed("auto|always|never")));
+            }
+            _ => {}
+        }
+        if hidden {
+            Ok(Some(Meta::Group(vec![Meta::Hide, Meta::Option(option)])))
         } else {
-            Ok(None)
+            Ok(Some(Meta::Option(option)))
         }
     }
 
@@ -549,6 +590,7 @@ impl<S: Scope, P> Builder<S, P> {
             .map_parse(|inner| Environment(inner, variable))
     }
 
+    #[allow(clippy::type_complexity)]
     pub fn many<T, I: default::Default + Extend<T>>(
         self,
     ) -> Builder<S, Many<P, I, impl Fn() -> I, impl Fn(&mut I, T)>>
@@ -603,6 +645,15 @@ impl<S: Scope, P> Builder<S, P> {
         }
     }
 
+    pub fn details(self, details: impl Into<Cow<'static, str>>) -> Self {
+        let details = details.into();
+        if details.chars().all(char::is_whitespace) {
+            self
+        } else {
+            self.meta(Meta::Details(details))
+        }
+    }
+
     pub fn line(self) -> Self {
         self.meta(Meta::Line)
     }
@@ -652,6 +703,32 @@ impl<S: scope::Node, P> Builder<S, P> {
         }
     }
 
+    pub fn topic(
+        self,
+        name: impl Into<Cow<'static, str>>,
+        text: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        let name = name.into();
+        if name.chars().all(char::is_whitespace) {
+            self
+        } else {
+            self.meta(Meta::Topic(name, text.into()))
+        }
+    }
+
+    pub fn example(
+        self,
+        command: impl Into<Cow<'static, str>>,
+        text: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        let command = command.into();
+        if command.chars().all(char::is_whitespace) {
+            self
+        } else {
+            self.meta(Meta::Example(command, text.into()))
+        }
+    }
+
     pub fn group<Q>(
         self,
         build: impl FnOnce(Builder<scope::Group, At>) -> Builder<scope::Group, Q>,
@@ -661,13 +738,13 @@ impl<S: scope::Node, P> Builder<S, P> {
     {
         let (scope, old, builder) = self.swap_both(scope::Group::new(), At(()));
         let (group, mut builder) = build(builder).swap_scope(scope);
-        let meta = Meta::from(group);
-        builder.scope.push(meta.clone(usize::MAX));
+        let index = builder.scope.len();
+        builder.scope.push(Meta::from(group));
         builder.try_map_parse(|new| {
             Ok(old?.push(With {
                 parse: new,
                 set: RegexSet::empty(),
-                meta,
+                meta: Slot::Index(index),
             }))
         })
     }
@@ -685,7 +762,8 @@ impl<S: scope::Node, P> Builder<S, P> {
         let mut meta = Meta::from(verb);
         let pair = builder.descend(&mut meta, true);
         builder.position = position;
-        builder.scope.push(meta.clone(1));
+        let index = builder.scope.len();
+        builder.scope.push(meta);
         builder.try_map_parse(|new| {
             let (indices, set) = pair?;
             Ok(old?.push(With {
@@ -693,7 +771,7 @@ impl<S: scope::Node, P> Builder<S, P> {
                     parse: new,
                     indices,
                 },
-                meta,
+                meta: Slot::Index(index),
                 set,
             }))
         })
@@ -710,13 +788,14 @@ impl<S: scope::Node, P> Builder<S, P> {
         let (option, mut builder) = build(builder.parse::<T>()).swap_scope(scope);
         let mut meta = Meta::from(option);
         let pair = builder.descend(&mut meta, false);
-        builder.scope.push(meta.clone(1));
+        let index = builder.scope.len();
+        builder.scope.push(meta);
         builder.try_map_parse(|new| {
             let (_, set) = pair?;
             Ok(old?.push(With {
                 parse: new,
                 set,
-                meta,
+                meta: Slot::Index(index),
             }))
         })
     }
@@ -727,6 +806,19 @@ impl<S: scope::Node, P> Builder<S, P> {
             .map(Meta::Options)
             .fold(self, Builder::meta)
     }
+
+    pub fn builtin(
+        self,
+        builtin: Builtin,
+        build: impl FnOnce(Builder<scope::Option, At>) -> Builder<scope::Option, At>,
+    ) -> Self {
+        let (scope, old, builder) = self.swap_both(scope::Option::new(), At(()));
+        let (mut option, mut builder) = build(builder).swap_scope(scope);
+        builder
+            .scope
+            .push(Meta::Builtin(builtin, take(&mut *option)));
+        builder.try_map_parse(|_| old)
+    }
 }
 
 impl<S: scope::Version, P> Builder<S, P> {
@@ -734,6 +826,19 @@ impl<S: scope::Version, P> Builder<S, P> {
         self.meta(Meta::Version(version.into()))
     }
 
+    pub fn metadata(
+        self,
+        key: impl Into<Cow<'static, str>>,
+        value: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        let (key, value) = (key.into(), value.into());
+        if key.chars().all(char::is_whitespace) || value.chars().all(char::is_whitespace) {
+            self
+        } else {
+            self.meta(Meta::Metadata(key, value))
+        }
+    }
+
     pub fn summary(self, summary: impl Into<Cow<'static, str>>) -> Self {
         let summary = summary.into();
         if summary.chars().all(char::is_whitespace) {
@@ -761,6 +866,12 @@ impl Builder<scope::Root> {
             parse: Ok(At(())),
             scope: scope::Root::new(),
             style: Box::new(style::Termion),
+            color: ColorChoice::Auto,
+            width: (0, usize::MAX),
+            suggestions: (3, 3),
+            pager: true,
+            markup: false,
+            locale: Box::new(English),
             position: 0,
         }
     }
@@ -802,12 +913,18 @@ impl<P> Builder<scope::Root, P> {
             short: builder.short,
             long: builder.long,
             style: builder.style,
+            color: builder.color,
+            width: builder.width,
+            suggestions: builder.suggestions,
+            pager: builder.pager,
+            markup: builder.markup,
+            locale: builder.locale,
             parse: With {
                 parse: Node {
                     indices,
                     parse: builder.parse?,
                 },
-                meta,
+                meta: Slot::Owned(meta),
                 set,
             },
         })
@@ -818,6 +935,53 @@ impl<P> Builder<scope::Root, P> {
         self
     }
 
+    pub fn color(mut self, color: ColorChoice) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn minimum_width(mut self, width: usize) -> Self {
+        self.width.0 = width;
+        self
+    }
+
+    pub fn maximum_width(mut self, width: usize) -> Self {
+        self.width.1 = width;
+        self
+    }
+
+    pub fn suggestions(mut self, ratio: usize, maximum: usize) -> Self {
+        self.suggestions = (ratio, maximum);
+        self
+    }
+
+    pub fn pager(mut self, pager: bool) -> Self {
+        self.pager = pager;
+        self
+    }
+
+    pub fn markup(mut self, markup: bool) -> Self {
+        self.markup = markup;
+        self
+    }
+
+    pub fn locale<L: Locale + 'static>(mut self, locale: L) -> Self {
+        self.locale = Box::new(locale);
+        self
+    }
+
+    pub fn locales(mut self, locales: impl IntoIterator<Item = Box<dyn Locale>>) -> Self {
+        if let Some(language) = locale::language(|key| env::var(key).ok()) {
+            if let Some(locale) = locales
+                .into_iter()
+                .find(|locale| locale.language().eq_ignore_ascii_case(&language))
+            {
+                self.locale = locale;
+            }
+        }
+        self
+    }
+
     pub fn name(self, name: impl Into<Cow<'static, str>>) -> Self {
         let name = name.into();
         if name.chars().all(char::is_whitespace) {
@@ -886,6 +1050,10 @@ impl<P> Builder<scope::Verb, P> {
         let meta = self.verb_name(name).map(|pair| Meta::Name(pair.0, pair.1));
         self.try_meta(meta)
     }
+
+    pub fn weight(self, weight: usize) -> Self {
+        self.meta(Meta::Weight(weight))
+    }
 }
 
 impl Builder<scope::Option, Value<Unit>> {
@@ -909,11 +1077,36 @@ impl Builder<scope::Option, Value<Unit>> {
         let format = self.convert(format);
         self.meta(Meta::Type(format)).map_parse(|_| Value {
             tag: tag.map(Into::into),
+            source: Source::Value,
+            limit: None,
             _marker: PhantomData,
         })
     }
 }
 
+impl<T> Builder<scope::Option, Value<T>> {
+    pub fn from_file(self) -> Self {
+        self.map_parse(|value| Value {
+            source: Source::File,
+            ..value
+        })
+    }
+
+    pub fn file_or_value(self) -> Self {
+        self.map_parse(|value| Value {
+            source: Source::Either,
+            ..value
+        })
+    }
+
+    pub fn limit(self, limit: u64) -> Self {
+        self.map_parse(|value| Value {
+            limit: Some(limit),
+            ..value
+        })
+    }
+}
+
 impl<P> Builder<scope::Option, P> {
     pub fn name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
         let name = name.into();
@@ -937,9 +1130,39 @@ impl<P> Builder<scope::Option, P> {
         self.meta(Meta::Swizzle)
     }
 
+    pub fn secret(self) -> Self {
+        self.meta(Meta::Secret)
+    }
+
+    pub fn weight(self, weight: usize) -> Self {
+        self.meta(Meta::Weight(weight))
+    }
+
     pub fn valid(self, pattern: impl Into<Cow<'static, str>>) -> Self {
         self.meta(Meta::Valid(pattern.into()))
     }
+
+    pub fn value_name(self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.meta(Meta::Value(name.into()))
+    }
+
+    pub fn prompt<T: FromStr>(
+        self,
+        prompt: impl Into<Cow<'static, str>>,
+    ) -> Builder<scope::Option, Prompt<P>>
+    where
+        P: Parse<Value = Option<T>>,
+    {
+        let prompt = prompt.into();
+        self.map_parse(|parse| Prompt(parse, prompt))
+    }
+
+    pub fn complete_with<F: Fn(&str) -> Vec<String>>(
+        self,
+        complete: F,
+    ) -> Builder<scope::Option, Complete<P, F>> {
+        self.map_parse(|parse| Complete(parse, complete))
+    }
 }
 
 fn type_name<T: 'static>() -> &'static str {
@@ -963,7 +1186,7 @@ fn type_name<T: 'static>() -> &'static str {
         if let Some(split) = name.split('<').next() {
             name = split;
         }
-        if let Some(split) = name.split(':').last() {
+        if let Some(split) = name.split(':').next_back() {
             name = split;
         }
         name
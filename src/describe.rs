@@ -0,0 +1,148 @@
+use crate::{
+    help,
+    meta::{Meta, Name},
+};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Command {
+    pub path: Vec<String>,
+    pub names: Vec<String>,
+    pub version: Option<String>,
+    pub metadata: Vec<(String, String)>,
+    pub summary: Option<String>,
+    pub help: Vec<String>,
+    pub details: Vec<String>,
+    pub usage: Vec<String>,
+    pub notes: Vec<String>,
+    pub topics: Vec<(String, String)>,
+    pub examples: Vec<(String, String)>,
+    pub group: Option<String>,
+    pub hidden: bool,
+    pub options: Vec<Argument>,
+    pub commands: Vec<Command>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Argument {
+    pub names: Vec<String>,
+    pub position: Option<usize>,
+    pub kind: Option<String>,
+    pub value: Option<String>,
+    pub default: Option<String>,
+    pub environment: Option<String>,
+    pub valid: Vec<String>,
+    pub require: Option<String>,
+    pub many: Option<Option<usize>>,
+    pub swizzle: bool,
+    pub secret: bool,
+    pub tags: Vec<String>,
+    pub summary: Option<String>,
+    pub help: Vec<String>,
+    pub details: Vec<String>,
+    pub group: Option<String>,
+    pub hidden: bool,
+}
+
+impl Command {
+    fn new(path: Vec<String>, metas: &[Meta], group: Option<&str>, hidden: bool) -> Self {
+        let mut command = Command {
+            path,
+            group: group.map(str::to_string),
+            hidden,
+            ..Default::default()
+        };
+        command.descend(metas, None, hidden, true);
+        command
+    }
+
+    fn descend(&mut self, metas: &[Meta], group: Option<&str>, hidden: bool, top: bool) {
+        let mut hide = hidden;
+        for meta in metas {
+            match meta {
+                Meta::Hide => hide = true,
+                Meta::Show => hide = hidden,
+                Meta::Name(Name::Short | Name::Long, value) if top => {
+                    self.names.push(value.to_string())
+                }
+                Meta::Version(value) if top => self.version = Some(value.to_string()),
+                Meta::Metadata(key, value) if top => {
+                    self.metadata.push((key.to_string(), value.to_string()))
+                }
+                Meta::Summary(value) if top => self.summary = Some(value.to_string()),
+                Meta::Help(value) if top => self.help.push(value.to_string()),
+                Meta::Details(value) if top => self.details.push(value.to_string()),
+                Meta::Usage(value) if top => self.usage.push(value.to_string()),
+                Meta::Note(value) if top => self.notes.push(value.to_string()),
+                Meta::Topic(name, text) => self.topics.push((name.to_string(), text.to_string())),
+                Meta::Example(command, text) if top => {
+                    self.examples.push((command.to_string(), text.to_string()))
+                }
+                Meta::Option(metas) => self.options.push(Argument::new(metas, group, hide)),
+                Meta::Verb(metas) => {
+                    let mut path = self.path.clone();
+                    path.extend(help::verb(meta).map(|name| name.to_string()));
+                    self.commands.push(Command::new(path, metas, group, hide));
+                }
+                Meta::Group(metas) => {
+                    let name = help::name(metas, Name::Plain).map(|name| name.as_ref());
+                    self.descend(metas, name.or(group), hide, false);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Argument {
+    fn new(metas: &[Meta], group: Option<&str>, hidden: bool) -> Self {
+        let mut argument = Argument {
+            group: group.map(str::to_string),
+            hidden,
+            ..Default::default()
+        };
+        for meta in metas {
+            match meta {
+                Meta::Name(Name::Short | Name::Long, value) => {
+                    argument.names.push(value.to_string())
+                }
+                Meta::Position(value) => argument.position = Some(*value),
+                Meta::Type(value) => argument.kind = Some(value.to_string()),
+                Meta::Value(value) => argument.value = Some(value.to_string()),
+                Meta::Default(_) if Meta::secret(metas) => {}
+                Meta::Default(value) => argument.default = Some(value.to_string()),
+                Meta::Environment(value) => argument.environment = Some(value.to_string()),
+                Meta::Valid(value) => argument.valid.push(value.to_string()),
+                Meta::Require(value) => {
+                    argument.require = Some(value.to_string());
+                    argument.tags.push("require".into());
+                }
+                Meta::Many(value) => {
+                    argument.many = Some(value.map(|value| value.get()));
+                    argument.tags.push("many".into());
+                }
+                Meta::Swizzle => {
+                    argument.swizzle = true;
+                    argument.tags.push("swizzle".into());
+                }
+                Meta::Secret => {
+                    argument.secret = true;
+                    argument.tags.push("secret".into());
+                }
+                Meta::Summary(value) => argument.summary = Some(value.to_string()),
+                Meta::Help(value) => argument.help.push(value.to_string()),
+                Meta::Details(value) => argument.details.push(value.to_string()),
+                _ => {}
+            }
+        }
+        argument
+    }
+}
+
+pub(crate) fn describe(root: &Meta) -> Command {
+    let name = help::name(root.children(), Name::Plain)
+        .map(|name| name.to_string())
+        .unwrap_or_else(|| "command".into());
+    Command::new(vec![name], root.children(), None, false)
+}
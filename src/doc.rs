@@ -0,0 +1,539 @@
+use crate::{
+    help,
+    locale::{Heading, Locale},
+    meta::{Meta, Name},
+    parse::Key,
+    style::Item,
+};
+use core::{
+    fmt::{self, Write},
+    mem::take,
+};
+use std::borrow::Cow;
+
+struct Markdown;
+struct Html;
+
+enum Span<'a> {
+    Text(&'a str),
+    Code(Cow<'a, str>),
+    Strong(&'a str),
+    Emphasis(&'a str),
+    Link(&'a str, String),
+}
+
+trait Markup {
+    fn begin(&self, buffer: &mut String, anchor: &str) -> fmt::Result;
+    fn end(&self, buffer: &mut String) -> fmt::Result;
+    fn heading(&self, buffer: &mut String, level: usize, spans: &[Span]) -> fmt::Result;
+    fn paragraph(&self, buffer: &mut String, lines: &[Vec<Span>]) -> fmt::Result;
+    fn block(&self, buffer: &mut String, text: &str) -> fmt::Result;
+    fn table(&self, buffer: &mut String, heads: &[&str], rows: &[Vec<Vec<Span>>]) -> fmt::Result;
+}
+
+struct Document<'a, M> {
+    buffer: &'a mut String,
+    format: &'a M,
+    root: &'a Meta,
+    locale: &'a dyn Locale,
+    markup: bool,
+}
+
+impl<M: Markup> Document<'_, M> {
+    fn paragraph(&mut self, text: &str) -> fmt::Result {
+        let mut lines = Vec::new();
+        let mut block: Option<Vec<&str>> = None;
+        for line in text.lines() {
+            if self.markup && line.trim() == "```" {
+                match block.take() {
+                    Some(code) => self.format.block(self.buffer, &code.join("\n"))?,
+                    None => {
+                        if !lines.is_empty() {
+                            self.format.paragraph(self.buffer, &take(&mut lines))?;
+                        }
+                        block = Some(Vec::new());
+                    }
+                }
+            } else if let Some(code) = &mut block {
+                code.push(line);
+            } else {
+                lines.push(self.spans(line));
+            }
+        }
+        if let Some(code) = block {
+            self.format.block(self.buffer, &code.join("\n"))?;
+        }
+        if lines.is_empty() {
+            Ok(())
+        } else {
+            self.format.paragraph(self.buffer, &lines)
+        }
+    }
+
+    fn spans<'b>(&self, line: &'b str) -> Vec<Span<'b>> {
+        help::spans(line, self.markup)
+            .into_iter()
+            .map(|(item, text)| match item {
+                Some(Item::Code) => Span::Code(Cow::Borrowed(text)),
+                Some(Item::Strong) => Span::Strong(text),
+                Some(Item::Emphasis) => Span::Emphasis(text),
+                _ => Span::Text(text),
+            })
+            .collect()
+    }
+
+    fn verb(&mut self, path: &[Key], meta: &Meta) -> fmt::Result {
+        let level = path.len();
+        let title = help::title(path, " ");
+        self.format.begin(self.buffer, &help::title(path, "-"))?;
+        match help::version(meta, 1).filter(|version| !version.is_empty()) {
+            Some(version) => self.format.heading(
+                self.buffer,
+                level,
+                &[
+                    Span::Text(&title),
+                    Span::Text(" "),
+                    Span::Code(Cow::Borrowed(&version)),
+                ],
+            )?,
+            None => self
+                .format
+                .heading(self.buffer, level, &[Span::Text(&title)])?,
+        }
+        for meta in Meta::visible(meta.children()) {
+            if let Meta::Summary(value) | Meta::Help(value) | Meta::Details(value) = meta {
+                self.paragraph(value)?;
+            }
+        }
+
+        let mut has = false;
+        for meta in Meta::visible(meta.children()) {
+            if let Meta::Usage(value) = meta {
+                self.format.block(self.buffer, value)?;
+                has = true;
+            }
+        }
+        if !has {
+            let synopsis = help::synopsis(self.root, meta, path, self.locale).ok_or(fmt::Error)?;
+            self.format.block(self.buffer, &synopsis)?;
+        }
+
+        self.section(path, meta.children(), level + 1, true)?;
+        self.examples(meta.children(), level + 1)?;
+        for meta in Meta::visible(meta.children()) {
+            if let Meta::Note(value) = meta {
+                self.paragraph(value)?;
+            }
+        }
+        self.format.end(self.buffer)
+    }
+
+    fn examples(&mut self, metas: &[Meta], level: usize) -> fmt::Result {
+        let mut has = false;
+        for meta in Meta::visible(metas) {
+            if let Meta::Example(command, text) = meta {
+                if !has {
+                    self.format.heading(
+                        self.buffer,
+                        level,
+                        &[Span::Text(&self.locale.heading(Heading::Examples))],
+                    )?;
+                    has = true;
+                }
+                if !text.chars().all(char::is_whitespace) {
+                    self.paragraph(text)?;
+                }
+                self.format.block(self.buffer, command)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn section(&mut self, path: &[Key], metas: &[Meta], level: usize, titles: bool) -> fmt::Result {
+        let Entries {
+            options,
+            verbs,
+            groups,
+        } = Entries::new(metas);
+        if !options.is_empty() {
+            if titles {
+                self.format.heading(
+                    self.buffer,
+                    level,
+                    &[Span::Text(&self.locale.heading(Heading::Options))],
+                )?;
+            }
+            self.options(&options)?;
+        }
+        if !verbs.is_empty() {
+            if titles {
+                self.format.heading(
+                    self.buffer,
+                    level,
+                    &[Span::Text(&self.locale.heading(Heading::Commands))],
+                )?;
+            }
+            self.verbs(path, &verbs)?;
+        }
+        for (name, metas) in groups {
+            self.format
+                .heading(self.buffer, level, &[Span::Text(name)])?;
+            for meta in Meta::visible(metas) {
+                if let Meta::Help(value) | Meta::Details(value) = meta {
+                    self.paragraph(value)?;
+                }
+            }
+            self.section(path, metas, level + 1, false)?;
+        }
+        Ok(())
+    }
+
+    fn options(&mut self, options: &[&[Meta]]) -> fmt::Result {
+        let mut rows = Vec::with_capacity(options.len());
+        for &metas in options {
+            let mut names = Vec::new();
+            let mut defaults = Vec::new();
+            let mut variables = Vec::new();
+            let mut patterns = Vec::new();
+            let mut positions = Vec::new();
+            for meta in Meta::visible(metas) {
+                match meta {
+                    Meta::Name(Name::Short | Name::Long, value) => {
+                        names.push(Cow::Borrowed(value.as_ref()))
+                    }
+                    Meta::Position(position) => positions.push(Cow::Owned(format!("[{position}]"))),
+                    Meta::Default(value) if !Meta::secret(metas) => {
+                        defaults.push(Cow::Borrowed(value.as_ref()))
+                    }
+                    Meta::Environment(value) => variables.push(Cow::Borrowed(value.as_ref())),
+                    Meta::Valid(value) => patterns.push(Cow::Borrowed(value.as_ref())),
+                    _ => {}
+                }
+            }
+            names.extend(positions);
+            rows.push(vec![
+                join(names),
+                join(help::kind(metas).map(|kind| Cow::Borrowed(kind.as_ref()))),
+                join(defaults),
+                join(variables),
+                join(patterns),
+                help::summary(metas)
+                    .map(|summary| self.spans(summary))
+                    .unwrap_or_default(),
+            ]);
+        }
+        let headings = [
+            Heading::Option,
+            Heading::Type,
+            Heading::Default,
+            Heading::Environment,
+            Heading::Valid,
+            Heading::Description,
+        ]
+        .map(|heading| self.locale.heading(heading));
+        let headings = headings.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+        self.format.table(self.buffer, &headings, &rows)
+    }
+
+    fn verbs(&mut self, path: &[Key], verbs: &[&Meta]) -> fmt::Result {
+        let mut rows = Vec::with_capacity(verbs.len());
+        for &verb in verbs {
+            let Some(name) = help::verb(verb) else {
+                continue;
+            };
+            let anchor = format!("{}-{name}", help::title(path, "-"));
+            rows.push(vec![
+                vec![Span::Link(name, anchor)],
+                help::summary(verb.children())
+                    .map(|summary| self.spans(summary))
+                    .unwrap_or_default(),
+            ]);
+        }
+        let headings =
+            [Heading::Command, Heading::Description].map(|heading| self.locale.heading(heading));
+        let headings = headings.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+        self.format.table(self.buffer, &headings, &rows)
+    }
+}
+
+impl Markup for Markdown {
+    fn begin(&self, buffer: &mut String, anchor: &str) -> fmt::Result {
+        writeln!(buffer, "<a id=\"{anchor}\"></a>")?;
+        writeln!(buffer)
+    }
+
+    fn end(&self, _: &mut String) -> fmt::Result {
+        Ok(())
+    }
+
+    fn heading(&self, buffer: &mut String, level: usize, spans: &[Span]) -> fmt::Result {
+        for _ in 0..level.clamp(1, 6) {
+            buffer.push('#');
+        }
+        buffer.push(' ');
+        self.spans(buffer, spans)?;
+        writeln!(buffer)?;
+        writeln!(buffer)
+    }
+
+    fn paragraph(&self, buffer: &mut String, lines: &[Vec<Span>]) -> fmt::Result {
+        for spans in lines {
+            let start = buffer.len();
+            self.spans(buffer, spans)?;
+            buffer.truncate(start + buffer[start..].trim_end().len());
+            writeln!(buffer)?;
+        }
+        writeln!(buffer)
+    }
+
+    fn block(&self, buffer: &mut String, text: &str) -> fmt::Result {
+        writeln!(buffer, "```text")?;
+        writeln!(buffer, "{text}")?;
+        writeln!(buffer, "```")?;
+        writeln!(buffer)
+    }
+
+    fn table(&self, buffer: &mut String, heads: &[&str], rows: &[Vec<Vec<Span>>]) -> fmt::Result {
+        for head in heads {
+            write!(buffer, "| {head} ")?;
+        }
+        writeln!(buffer, "|")?;
+        for _ in heads {
+            write!(buffer, "| --- ")?;
+        }
+        writeln!(buffer, "|")?;
+        for row in rows {
+            for cell in row {
+                write!(buffer, "| ")?;
+                self.spans(buffer, cell)?;
+                write!(buffer, " ")?;
+            }
+            writeln!(buffer, "|")?;
+        }
+        writeln!(buffer)
+    }
+}
+
+impl Markdown {
+    fn spans(&self, buffer: &mut String, spans: &[Span]) -> fmt::Result {
+        for span in spans {
+            match span {
+                Span::Text(text) => {
+                    for letter in text.chars() {
+                        if matches!(letter, '\\' | '*' | '_' | '`') {
+                            buffer.push('\\');
+                        }
+                        Self::escape(buffer, letter.encode_utf8(&mut [0; 4]));
+                    }
+                }
+                Span::Code(text) => {
+                    buffer.push('`');
+                    Self::escape(buffer, text);
+                    buffer.push('`');
+                }
+                Span::Strong(text) => {
+                    buffer.push_str("**");
+                    Self::escape(buffer, text);
+                    buffer.push_str("**");
+                }
+                Span::Emphasis(text) => {
+                    buffer.push('*');
+                    Self::escape(buffer, text);
+                    buffer.push('*');
+                }
+                Span::Link(text, anchor) => {
+                    buffer.push_str("[`");
+                    Self::escape(buffer, text);
+                    write!(buffer, "`](#{anchor})")?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn escape(buffer: &mut String, text: &str) {
+        for letter in text.chars() {
+            match letter {
+                '|' => buffer.push_str("\\|"),
+                '<' => buffer.push_str("&lt;"),
+                '>' => buffer.push_str("&gt;"),
+                '\n' => buffer.push_str("<br>"),
+                letter => buffer.push(letter),
+            }
+        }
+    }
+}
+
+impl Markup for Html {
+    fn begin(&self, buffer: &mut String, anchor: &str) -> fmt::Result {
+        write!(buffer, "<section id=\"")?;
+        Self::escape(buffer, anchor);
+        writeln!(buffer, "\">")
+    }
+
+    fn end(&self, buffer: &mut String) -> fmt::Result {
+        writeln!(buffer, "</section>")
+    }
+
+    fn heading(&self, buffer: &mut String, level: usize, spans: &[Span]) -> fmt::Result {
+        let level = level.clamp(1, 6);
+        write!(buffer, "<h{level}>")?;
+        self.spans(buffer, spans)?;
+        writeln!(buffer, "</h{level}>")
+    }
+
+    fn paragraph(&self, buffer: &mut String, lines: &[Vec<Span>]) -> fmt::Result {
+        buffer.push_str("<p>");
+        for (index, spans) in lines.iter().enumerate() {
+            if index > 0 {
+                buffer.push_str("<br>");
+            }
+            self.spans(buffer, spans)?;
+        }
+        writeln!(buffer, "</p>")
+    }
+
+    fn block(&self, buffer: &mut String, text: &str) -> fmt::Result {
+        buffer.push_str("<pre><code>");
+        Self::escape(buffer, text);
+        writeln!(buffer, "</code></pre>")
+    }
+
+    fn table(&self, buffer: &mut String, heads: &[&str], rows: &[Vec<Vec<Span>>]) -> fmt::Result {
+        writeln!(buffer, "<table>")?;
+        buffer.push_str("<thead><tr>");
+        for head in heads {
+            buffer.push_str("<th>");
+            Self::escape(buffer, head);
+            buffer.push_str("</th>");
+        }
+        writeln!(buffer, "</tr></thead>")?;
+        writeln!(buffer, "<tbody>")?;
+        for row in rows {
+            buffer.push_str("<tr>");
+            for cell in row {
+                buffer.push_str("<td>");
+                self.spans(buffer, cell)?;
+                buffer.push_str("</td>");
+            }
+            writeln!(buffer, "</tr>")?;
+        }
+        writeln!(buffer, "</tbody>")?;
+        writeln!(buffer, "</table>")
+    }
+}
+
+impl Html {
+    fn spans(&self, buffer: &mut String, spans: &[Span]) -> fmt::Result {
+        for span in spans {
+            match span {
+                Span::Text(text) => Self::escape(buffer, text),
+                Span::Code(text) => {
+                    buffer.push_str("<code>");
+                    Self::escape(buffer, text);
+                    buffer.push_str("</code>");
+                }
+                Span::Strong(text) => {
+                    buffer.push_str("<strong>");
+                    Self::escape(buffer, text);
+                    buffer.push_str("</strong>");
+                }
+                Span::Emphasis(text) => {
+                    buffer.push_str("<em>");
+                    Self::escape(buffer, text);
+                    buffer.push_str("</em>");
+                }
+                Span::Link(text, anchor) => {
+                    buffer.push_str("<a href=\"#");
+                    Self::escape(buffer, anchor);
+                    buffer.push_str("\"><code>");
+                    Self::escape(buffer, text);
+                    buffer.push_str("</code></a>");
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn escape(buffer: &mut String, text: &str) {
+        for letter in text.chars() {
+            match letter {
+                '&' => buffer.push_str("&amp;"),
+                '<' => buffer.push_str("&lt;"),
+                '>' => buffer.push_str("&gt;"),
+                '"' => buffer.push_str("&quot;"),
+                letter => buffer.push(letter),
+            }
+        }
+    }
+}
+
+pub(crate) fn markdown(
+    root: &Meta,
+    locale: &dyn Locale,
+    markup: bool,
+) -> Result<String, fmt::Error> {
+    render(root, &Markdown, locale, markup)
+}
+
+pub(crate) fn html(root: &Meta, locale: &dyn Locale, markup: bool) -> Result<String, fmt::Error> {
+    render(root, &Html, locale, markup)
+}
+
+fn render<M: Markup>(
+    root: &Meta,
+    format: &M,
+    locale: &dyn Locale,
+    markup: bool,
+) -> Result<String, fmt::Error> {
+    let mut buffer = String::new();
+    let mut document = Document {
+        buffer: &mut buffer,
+        format,
+        root,
+        locale,
+        markup,
+    };
+    help::walk(root, &mut |path, meta| document.verb(path, meta))?;
+    Ok(buffer)
+}
+
+#[derive(Default)]
+struct Entries<'a> {
+    options: Vec<&'a [Meta]>,
+    verbs: Vec<&'a Meta>,
+    groups: Vec<(&'a str, &'a [Meta])>,
+}
+
+impl<'a> Entries<'a> {
+    fn new(metas: &'a [Meta]) -> Self {
+        let mut entries = Self::default();
+        entries.descend(metas);
+        entries
+    }
+
+    fn descend(&mut self, metas: &'a [Meta]) {
+        for meta in Meta::visible(metas) {
+            match meta {
+                Meta::Option(metas) => self.options.push(metas),
+                Meta::Verb(_) => self.verbs.push(meta),
+                Meta::Group(metas) => match help::name(metas, Name::Plain) {
+                    Some(name) => self.groups.push((name, metas)),
+                    None => self.descend(metas),
+                },
+                _ => {}
+            }
+        }
+    }
+}
+
+fn join<'a>(values: impl IntoIterator<Item = Cow<'a, str>>) -> Vec<Span<'a>> {
+    let mut spans = Vec::new();
+    for (index, value) in values.into_iter().enumerate() {
+        if index > 0 {
+            spans.push(Span::Text(", "));
+        }
+        spans.push(Span::Code(value));
+    }
+    spans
+}
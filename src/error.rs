@@ -1,19 +1,28 @@
-use crate::parse::Key;
+use crate::{
+    locale::{Locale, Localized},
+    meta::Builtin,
+    parse::Key,
+};
 use core::fmt;
 use std::{borrow::Cow, collections::VecDeque, error, mem::replace};
 
 #[derive(Clone, PartialEq)]
 pub enum Error {
     Help(Option<String>),
+    Brief,
     Version(Option<String>),
+    Verbose,
     Author(Option<String>),
     License(Option<String>),
+    Complete(Vec<Cow<'static, str>>),
+    Search(Cow<'static, str>),
 
     MissingOptionValue(Option<Cow<'static, str>>, Vec<Key>),
     MissingRequiredOption(Vec<Key>, Option<Key>),
     MissingRequiredValue(Vec<Key>, Option<Cow<'static, str>>),
     DuplicateOption(Vec<Key>),
     UnrecognizedArgument(Cow<'static, str>, Vec<(Cow<'static, str>, usize)>),
+    MisplacedArgument(Cow<'static, str>, Vec<Key>),
     ExcessArguments(VecDeque<Cow<'static, str>>),
     DuplicateName(String),
     Format(fmt::Error),
@@ -43,7 +52,14 @@ pub enum Error {
     InvalidOptionType(Cow<'static, str>),
     InvalidInitialization,
     InvalidOptionValue(Cow<'static, str>, Vec<String>, Vec<Key>),
+    FailedToReadOptionFile(Cow<'static, str>, Cow<'static, str>, Vec<Key>),
+    OptionFileTooLarge(Cow<'static, str>, u64, Vec<Key>),
+    FailedToParseOptionFile(Cow<'static, str>, Option<Cow<'static, str>>, Vec<Key>),
+    InvalidOptionFile(Cow<'static, str>, Vec<String>, Vec<Key>),
     InvalidArgument(Cow<'static, str>, Vec<String>, Vec<Key>),
+    InvalidThemeEntry(Cow<'static, str>),
+    InvalidBuiltinOption(Builtin),
+    InvalidExample(Cow<'static, str>, Box<Error>),
 }
 
 impl error::Error for Error {}
@@ -56,15 +72,29 @@ impl fmt::Debug for Error {
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.english(f)
+    }
+}
+
+impl Error {
+    pub fn localize<'a>(&'a self, locale: &'a dyn Locale) -> Localized<'a> {
+        Localized(self, locale)
+    }
+
+    pub(crate) fn english(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Error::Help(Some(help)) => write!(f, "{help}")?,
             Error::Help(None) => write!(f, "Missing help.")?,
+            Error::Brief => write!(f, "Missing short help.")?,
             Error::Version(Some(version)) => write!(f, "{version}")?,
             Error::Version(None) => write!(f, "Missing version.")?,
+            Error::Verbose => write!(f, "Missing verbose version.")?,
             Error::Author(Some(author)) => write!(f, "{author}")?,
             Error::Author(None) => write!(f, "Missing author.")?,
             Error::License(Some(author)) => write!(f, "{author}")?,
             Error::License(None) => write!(f, "Missing license.")?,
+            Error::Complete(candidates) => write_join(f, "", "", "\n", candidates)?,
+            Error::Search(keyword) => write!(f, "Missing search results for '{keyword}'.")?,
 
             Error::InvalidArgument(argument, patterns, path) => {
                 write!(f, "Invalid argument '{argument}'")?;
@@ -77,6 +107,13 @@ impl fmt::Display for Error {
                 let suggestions = suggestions.iter().map(|(suggestion, _)| format!("'{suggestion}'"));
                 write_join(f, " Similar matches: ", ".", ", ", suggestions)?;
             }
+            Error::MisplacedArgument(argument, path) => {
+                write!(f, "'{argument}' is an option of '")?;
+                write_join(f, "", "", "", path.last())?;
+                write!(f, "'; did you mean '")?;
+                write_join(f, "", " ", " ", path)?;
+                write!(f, "{argument}'?")?;
+            }
             Error::ExcessArguments(arguments) => {
                 write!(f, "Excess arguments '")?;
                 let mut join = false;
@@ -147,6 +184,42 @@ impl fmt::Display for Error {
             Error::InvalidVerbName(name) => write!(f, "Invalid verb name '{name}'. A valid verb name is non-empty and contains only ascii characters.")?,
             Error::InvalidOptionName(name) => write!(f, "Invalid option name '{name}'. A valid option name is non-empty and contains only ascii characters.")?,
             Error::InvalidOptionType(type_name) => write!(f, "Invalid option type '{type_name}'.")?,
+            Error::FailedToReadOptionFile(file, reason, path) => {
+                match file.as_ref() {
+                    "-" => write!(f, "Failed to read standard input")?,
+                    file => write!(f, "Failed to read file '{file}'")?,
+                }
+                write_join(f, " for option '", "'", " ", path)?;
+                write!(f, ": {reason}.")?;
+            }
+            Error::OptionFileTooLarge(file, limit, path) => {
+                match file.as_ref() {
+                    "-" => write!(f, "Standard input")?,
+                    file => write!(f, "File '{file}'")?,
+                }
+                write_join(f, " for option '", "'", " ", path)?;
+                write!(f, " exceeds the size limit of {limit} bytes.")?;
+            }
+            Error::FailedToParseOptionFile(file, type_name, path) => {
+                match file.as_ref() {
+                    "-" => write!(f, "Failed to parse standard input")?,
+                    file => write!(f, "Failed to parse file '{file}'")?,
+                }
+                if let Some(type_name) = type_name {
+                    write!(f, " as type '{type_name}'")?;
+                }
+                write_join(f, " for option '", "'", " ", path)?;
+                write!(f, ".")?;
+            }
+            Error::InvalidOptionFile(file, patterns, path) => {
+                match file.as_ref() {
+                    "-" => write!(f, "Invalid standard input")?,
+                    file => write!(f, "Invalid file '{file}'")?,
+                }
+                write_join(f, " for option '", "'", " ", path)?;
+                write!(f, ".")?;
+                write_join(f, " Content must match pattern '", "'.", " | ", patterns)?;
+            }
             Error::InvalidOptionValue(value, patterns, path) => {
                 write!(f, "Invalid value '{value}'")?;
                 write_join(f, " for option '", "'", " ", path)?;
@@ -161,10 +234,13 @@ impl fmt::Display for Error {
             Error::MissingShortOptionNameForSwizzling => write!(f, "Missing short option name for swizzling. A valid short option name has only a single ascii character.")?,
             Error::InvalidSwizzleOption(value) => write!(f, "Invalid swizzle option '{value}'. A valid swizzle option is tagged for swizzling, has a short name and is of type 'boolean'.")?,
             Error::InvalidInitialization => write!(f, "Invalid initialization.")?,
+            Error::InvalidExample(example, error) => write!(f, "Invalid example '{example}': {error}")?,
+            Error::InvalidBuiltinOption(builtin) => write!(f, "Invalid customization of built-in option '{}'. A built-in option only accepts names, help, details, visibility and a weight.", format!("{builtin:?}").to_lowercase())?,
+            Error::InvalidThemeEntry(entry) => write!(f, "Invalid theme entry '{entry}'. A valid entry is a preset ('dark' or 'light') or an item followed by ':' and a list of '#rrggbb', 'on #rrggbb', 'bold', 'italic', 'faint' or 'underline'.")?,
 
-            Error::Format(error) => error.fmt(f)?,
-            Error::Regex(error) => error.fmt(f)?,
-            Error::Other(error) => error.fmt(f)?,
+            Error::Format(error) => fmt::Display::fmt(error, f)?,
+            Error::Regex(error) => fmt::Display::fmt(error, f)?,
+            Error::Other(error) => fmt::Display::fmt(error, f)?,
         }
         Ok(())
     }
@@ -1,28 +1,32 @@
 use orn::Or2;
 
 use crate::{
+    locale::{Label, Locale},
     meta::{Meta, Name},
     parse::Key,
-    style::{Format, Item, Line, Style},
+    spell::Spell,
+    style::{Format, Hyperlink, Item, Line, Plain, Style},
 };
 use core::{
     fmt::{self, Write},
     mem::{replace, take},
     slice::from_ref,
 };
-use std::{
-    borrow::Cow,
-    fs,
-    ops::{ControlFlow, Deref},
-};
+use std::{borrow::Cow, fs, ops::Deref};
+use unicode_segmentation::UnicodeSegmentation;
 
 struct Helper<'a, S: Style + ?Sized> {
     buffer: &'a mut String,
     path: &'a [Key],
     style: &'a S,
+    locale: &'a dyn Locale,
+    brief: bool,
+    markup: bool,
     indent: usize,
 }
 
+const STACK: usize = 24;
+
 #[derive(Default)]
 struct Columns {
     short: usize,
@@ -30,6 +34,15 @@ struct Columns {
     types: usize,
 }
 
+#[derive(Default)]
+struct Synopsis<'a> {
+    options: bool,
+    named: Vec<(&'a str, Option<&'a str>)>,
+    positions: Vec<(&'a str, bool, bool)>,
+    requires: Vec<&'a str>,
+    verbs: Option<bool>,
+}
+
 struct Wrap<F>(F);
 
 impl<F: Format> fmt::Display for Wrap<F> {
@@ -39,6 +52,56 @@ impl<F: Format> fmt::Display for Wrap<F> {
     }
 }
 
+impl<'a> Synopsis<'a> {
+    fn descend(&mut self, metas: &'a [Meta]) {
+        let mut require = None;
+        let mut verbs = false;
+        for meta in Meta::visible(metas) {
+            match meta {
+                Meta::Require(value) => require = Some(value.as_ref()),
+                Meta::Option(metas) => self.option(metas),
+                Meta::Verb(_) => verbs = true,
+                Meta::Group(metas) => self.descend(metas),
+                _ => {}
+            }
+        }
+        if verbs {
+            self.verbs = Some(self.verbs.unwrap_or(false) || require.is_some());
+        } else if let Some(require) = require {
+            self.requires.push(require);
+        }
+    }
+
+    fn option(&mut self, metas: &'a [Meta]) {
+        let (mut short, mut long, mut value, mut kind) = (None, None, None, None);
+        let (mut position, mut require, mut many) = (false, false, false);
+        for meta in Meta::visible(metas) {
+            match meta {
+                Meta::Name(Name::Short, name) => short = short.or(Some(name.as_ref())),
+                Meta::Name(Name::Long, name) => long = long.or(Some(name.as_ref())),
+                Meta::Value(name) => value = Some(name.as_ref()),
+                Meta::Type(name) => kind = Some(name.as_ref()),
+                Meta::Position(_) => position = true,
+                Meta::Require(_) => require = true,
+                Meta::Many(_) => many = true,
+                _ => {}
+            }
+        }
+
+        if position {
+            let name = value
+                .or_else(|| long.map(|long| long.trim_start_matches('-')))
+                .or(kind)
+                .unwrap_or("value");
+            self.positions.push((name, require, many));
+        } else if let (true, Some(name)) = (require, long.or(short)) {
+            self.named.push((name, value.or(kind)));
+        } else {
+            self.options = true;
+        }
+    }
+}
+
 impl<'a, S: Style + ?Sized + 'a> Helper<'a, S> {
     fn space(&mut self, width: usize) -> Result<usize, fmt::Error> {
         for _ in 0..width {
@@ -47,20 +110,23 @@ impl<'a, S: Style + ?Sized + 'a> Helper<'a, S> {
         Ok(width)
     }
 
-    fn own(&mut self) -> Helper<S> {
+    fn own(&mut self) -> Helper<'_, S> {
         Helper {
             buffer: self.buffer,
             path: self.path,
             style: self.style,
+            locale: self.locale,
+            brief: self.brief,
+            markup: self.markup,
             indent: self.indent,
         }
     }
 
-    fn indent(&mut self) -> Helper<S> {
+    fn indent(&mut self) -> Helper<'_, S> {
         self.indent_with(self.style.indent())
     }
 
-    fn indent_with(&mut self, by: usize) -> Helper<S> {
+    fn indent_with(&mut self, by: usize) -> Helper<'_, S> {
         let mut helper = self.own();
         helper.indent += by;
         helper
@@ -145,7 +211,18 @@ impl<'a, S: Style + ?Sized + 'a> Helper<'a, S> {
         prefix: impl Format,
         suffix: impl Format,
         separator: impl Format,
-        mut find: impl FnMut(&Meta) -> Option<Cow<str>>,
+        find: impl FnMut(&Meta) -> Option<Cow<str>>,
+    ) -> Result<usize, fmt::Error> {
+        self.list(metas, prefix, suffix, separator, find)
+    }
+
+    fn list<'m, F: Format>(
+        &mut self,
+        metas: &'m [Meta],
+        prefix: impl Format,
+        suffix: impl Format,
+        separator: impl Format,
+        mut find: impl FnMut(&'m Meta) -> Option<F>,
     ) -> Result<usize, fmt::Error> {
         let mut width = 0;
         let mut prefix = Some(prefix);
@@ -183,25 +260,28 @@ impl<'a, S: Style + ?Sized + 'a> Helper<'a, S> {
                 Some(prefix) if !replace(has, true) => {
                     width += self.write(prefix)?;
                 }
-                _ => {
-                    width += self.write_line(())?;
-                    *cursor = self.indentation()?;
-                    width += self.write(&wrap)?;
-                }
+                _ => width += self.break_line(&wrap, cursor)?,
             }
 
             let mut has = false;
             for word in line.split(' ') {
-                if replace(&mut has, true) {
+                let space = usize::from(replace(&mut has, true));
+                if *cursor + space + word.width() > self.style.width() {
+                    width += self.break_line(&wrap, cursor)?;
+                } else if space > 0 {
                     width += self.write(" ")?;
+                    *cursor += space;
                 }
-
-                if *cursor + word.len() > self.style.width() {
-                    width += self.write_line(())?;
-                    *cursor = self.indentation()?;
-                    width += self.write(&wrap)?;
+                if *cursor + word.width() > self.style.width() {
+                    for (index, grapheme) in word.graphemes(true).enumerate() {
+                        if index > 0 && *cursor + grapheme.width() > self.style.width() {
+                            width += self.break_line(&wrap, cursor)?;
+                        }
+                        *cursor += self.write(grapheme)?;
+                    }
+                } else {
+                    *cursor += self.write(word)?;
                 }
-                *cursor += self.write(word)?;
             }
         }
         if width > 0 {
@@ -210,53 +290,183 @@ impl<'a, S: Style + ?Sized + 'a> Helper<'a, S> {
         Ok(width)
     }
 
-    fn description(
+    fn break_line(&mut self, wrap: impl Format, cursor: &mut usize) -> Result<usize, fmt::Error> {
+        let width = self.write_line(())?;
+        *cursor = self.indentation()?;
+        Ok(width + self.write(wrap)?)
+    }
+
+    fn text(
+        &mut self,
+        value: &str,
+        item: Item,
+        lead: impl Format,
+        cursor: &mut usize,
+        has: &mut bool,
+    ) -> Result<usize, fmt::Error> {
+        if value.is_empty() {
+            return Ok(0);
+        }
+        let mut width = 0;
+        let mut block = false;
+        for line in value.split('\n') {
+            if self.markup && line.trim() == "```" {
+                block = !block;
+                continue;
+            }
+            if replace(has, true) {
+                width += self.break_line(&lead, cursor)?;
+            } else {
+                width += self.write(&lead)?;
+            }
+            width += self.write(self.style.begin(item))?;
+            let content = line.trim_start();
+            if block {
+                let count = self.write((
+                    self.style.begin(Item::Block),
+                    line,
+                    self.style.end(Item::Block),
+                    self.style.begin(item),
+                ))?;
+                *cursor += count;
+                width += count;
+            } else if let Some(bullet) = content
+                .strip_prefix("- ")
+                .or_else(|| content.strip_prefix("* "))
+                .filter(|_| self.markup)
+            {
+                let hang = self.write((
+                    &line[..line.len() - content.len()],
+                    self.style.begin(Item::Bullet),
+                    self.style.end(Item::Bullet),
+                    self.style.begin(item),
+                    ' ',
+                ))?;
+                *cursor += hang;
+                width += hang + self.inline(bullet, item, &lead, hang, cursor)?;
+            } else {
+                width += self.inline(line, item, &lead, 0, cursor)?;
+            }
+        }
+        width += self.write(self.style.end(item))?;
+        Ok(width)
+    }
+
+    fn inline(
         &mut self,
-        metas: &[Meta],
-        prefix: impl Format,
-        suffix: impl Format,
-        line: impl Format,
+        line: &str,
+        item: Item,
+        lead: impl Format,
+        hang: usize,
+        cursor: &mut usize,
+    ) -> Result<usize, fmt::Error> {
+        let mut width = 0;
+        for (span, text) in spans(line, self.markup) {
+            if let Some(span) = span {
+                width += self.write(self.style.begin(span))?;
+            }
+            for (index, word) in text.split(' ').enumerate() {
+                let space = usize::from(index > 0);
+                if *cursor + space + word.width() > self.style.width() {
+                    width += self.fold(&lead, hang, (item, span), cursor)?;
+                } else if space > 0 {
+                    width += self.write(" ")?;
+                    *cursor += space;
+                }
+                if *cursor + word.width() > self.style.width() {
+                    for (index, grapheme) in word.graphemes(true).enumerate() {
+                        if index > 0 && *cursor + grapheme.width() > self.style.width() {
+                            width += self.fold(&lead, hang, (item, span), cursor)?;
+                        }
+                        let count = self.write(grapheme)?;
+                        *cursor += count;
+                        width += count;
+                    }
+                } else {
+                    let count = self.write(self.link(word))?;
+                    *cursor += count;
+                    width += count;
+                }
+            }
+            if let Some(span) = span {
+                width += self.write((self.style.end(span), self.style.begin(item)))?;
+            }
+        }
+        Ok(width)
+    }
+
+    fn link<'b>(&self, word: &'b str) -> (Hyperlink<'b, &'b str>, &'b str) {
+        let target = word.trim_end_matches(['.', ',', ';', ':', '!', '?', ')']);
+        let url = ["https://", "http://"]
+            .iter()
+            .any(|scheme| target.len() > scheme.len() && target.starts_with(scheme));
+        let link = (url && self.style.hyperlinks()).then_some(target);
+        (Hyperlink(link, target), &word[target.len()..])
+    }
+
+    fn fold(
+        &mut self,
+        lead: impl Format,
+        hang: usize,
+        (item, span): (Item, Option<Item>),
+        cursor: &mut usize,
     ) -> Result<usize, fmt::Error> {
+        let mut width = self.break_line(lead, cursor)?;
+        *cursor += self.space(hang)?;
+        width += self.write(self.style.begin(item))?;
+        if let Some(span) = span {
+            width += self.write(self.style.begin(span))?;
+        }
+        Ok(width)
+    }
+
+    fn description(&mut self, metas: &[Meta], lead: impl Format) -> Result<usize, fmt::Error> {
         let mut count = 0;
         for meta in Meta::visible(metas) {
             if let Meta::Summary(value) = meta {
                 if !value.chars().all(char::is_whitespace) {
                     count += self.write_line(())?;
                     count += self.indentation()?;
-                    count += self.wrap(value, &prefix, "", &line, &mut 0, &mut false)?;
+                    count += self.text(value, Item::Description, &lead, &mut 0, &mut false)?;
                 }
             }
         }
-        if count > 0 {
-            count += self.write(suffix)?;
-        }
         Ok(count)
     }
 
     fn summary(
         &mut self,
         metas: &[Meta],
-        prefix: impl Format,
-        suffix: impl Format,
-        mut cursor: usize,
+        item: Item,
+        cursor: &mut usize,
     ) -> Result<usize, fmt::Error> {
         let mut has = false;
         let mut width = 0;
         for meta in Meta::visible(metas) {
             if let Meta::Summary(value) = meta {
-                width += self.wrap(value, &prefix, &suffix, "", &mut cursor, &mut has)?;
+                let value = self.line(value);
+                width += self.text(value, item, "", cursor, &mut has)?;
             }
         }
         if width == 0 {
             for meta in Meta::visible(metas) {
                 if let Meta::Help(value) = meta {
-                    width += self.wrap(value, &prefix, &suffix, "", &mut cursor, &mut has)?;
+                    let value = self.line(value);
+                    width += self.text(value, item, "", cursor, &mut has)?;
                 }
             }
         }
         Ok(width)
     }
 
+    fn line<'b>(&self, value: &'b str) -> &'b str {
+        if self.brief {
+            value.lines().next().unwrap_or_default()
+        } else {
+            value
+        }
+    }
+
     fn usage(
         &mut self,
         root: &Meta,
@@ -264,60 +474,56 @@ impl<'a, S: Style + ?Sized + 'a> Helper<'a, S> {
         prefix: impl Format,
         suffix: impl Format,
     ) -> Result<usize, fmt::Error> {
-        let mut width = self.join(metas, &prefix, &suffix, " ", |meta| match meta {
+        let width = self.join(metas, &prefix, &suffix, " ", |meta| match meta {
             Meta::Usage(value) => Some(Cow::Borrowed(value)),
             _ => None,
         })?;
         if width == 0 {
-            width += self.write(prefix)?;
-            width += self.write("Usage:")?;
-            for key in root.key().as_ref().into_iter().chain(self.path) {
-                width += self.write((' ', key))?;
-            }
-
-            match Meta::descend(
-                metas,
-                (),
-                false,
-                usize::MAX,
-                |state, meta| match meta {
-                    Meta::Option(_) => ControlFlow::Break(self.write(" [OPTIONS]")),
-                    _ => ControlFlow::Continue(state),
-                },
-                |state, _| ControlFlow::Continue(state),
-            ) {
-                ControlFlow::Break(result) => width += result?,
-                ControlFlow::Continue(_) => {}
-            }
+            Ok(self.write((prefix, self.locale.usage()))?
+                + self.synopsis(root, metas)?
+                + self.write(suffix)?)
+        } else {
+            Ok(width)
+        }
+    }
 
-            fn control(result: Result<usize, fmt::Error>) -> ControlFlow<fmt::Error, usize> {
-                result.map_or_else(ControlFlow::Break, ControlFlow::Continue)
-            }
+    fn synopsis(&mut self, root: &Meta, metas: &[Meta]) -> Result<usize, fmt::Error> {
+        let mut width = 0;
+        if let Some(name) = name(root.children(), Name::Plain) {
+            width += self.write((' ', name.as_ref()))?;
+        }
+        for key in self.path {
+            width += self.write((' ', key))?;
+        }
 
-            match Meta::descend(
-                metas,
-                None,
-                false,
-                usize::MAX,
-                |state, _| ControlFlow::Continue(state),
-                |state, meta| match meta {
-                    Meta::Require(value) => ControlFlow::Continue(Some(value)),
-                    Meta::Group(_) => {
-                        if let Some(value) = state {
-                            width += control(self.write((' ', '<', value, '>')))?;
-                        }
-                        ControlFlow::Continue(None)
-                    }
-                    Meta::Option(_) | Meta::Verb(_) => ControlFlow::Continue(None),
-                    _ => ControlFlow::Continue(state),
-                },
-            ) {
-                ControlFlow::Break(error) => Err(error),
-                ControlFlow::Continue(_) => Ok(width + self.write(suffix)?),
+        let mut synopsis = Synopsis::default();
+        synopsis.descend(metas);
+        if synopsis.options {
+            width += self.write((" [", self.locale.label(Label::Options), ']'))?;
+        }
+        for (name, value) in synopsis.named {
+            width += self.write((' ', name))?;
+            if let Some(value) = value {
+                width += self.write((" <", value, '>'))?;
             }
-        } else {
-            Ok(width)
         }
+        for (name, require, many) in synopsis.positions {
+            width += match (require, many) {
+                (true, false) => self.write((" <", name, '>'))?,
+                (true, true) => self.write((" <", name, ">..."))?,
+                (false, false) => self.write((" [", name, ']'))?,
+                (false, true) => self.write((" [", name, "]..."))?,
+            };
+        }
+        for require in synopsis.requires {
+            width += self.write((" <", require, '>'))?;
+        }
+        width += match synopsis.verbs {
+            Some(true) => self.write((" <", self.locale.label(Label::Command), '>'))?,
+            Some(false) => self.write((" [", self.locale.label(Label::Command), ']'))?,
+            None => 0,
+        };
+        Ok(width)
     }
 
     fn columns(&self, metas: &[Meta], depth: usize) -> Columns {
@@ -332,13 +538,13 @@ impl<'a, S: Style + ?Sized + 'a> Helper<'a, S> {
                     columns.short += 4 + if replace(&mut short, true) { 2 } else { 0 }
                 }
                 Meta::Name(Name::Short, value) if depth == 0 => {
-                    columns.short += value.len() + if replace(&mut short, true) { 2 } else { 0 }
+                    columns.short += value.width() + if replace(&mut short, true) { 2 } else { 0 }
                 }
                 Meta::Name(Name::Long, value) if depth == 0 => {
-                    columns.long += value.len() + if replace(&mut long, true) { 2 } else { 0 }
+                    columns.long += value.width() + if replace(&mut long, true) { 2 } else { 0 }
                 }
                 Meta::Type(value) if depth == 0 => {
-                    columns.types = value.len();
+                    columns.types = value.width();
                     columns.types += self.style.begin(Item::Type).width();
                     columns.types += self.style.end(Item::Type).width();
                 }
@@ -355,20 +561,24 @@ impl<'a, S: Style + ?Sized + 'a> Helper<'a, S> {
     }
 
     fn tags(&mut self, metas: &[Meta]) -> Result<usize, fmt::Error> {
+        let secret = Meta::secret(metas);
         let mut width = self.join(metas, "", "", ", ", |meta| match meta {
             Meta::Require(_) => Some(Cow::Borrowed("require")),
             Meta::Swizzle => Some(Cow::Borrowed("swizzle")),
             Meta::Many(_) => Some(Cow::Borrowed("many")),
+            Meta::Secret => Some(Cow::Borrowed("secret")),
             _ => None,
         })?;
         let prefix = if width > 0 { ", " } else { "" };
-        width += self.join(metas, (prefix, "valid: "), "", " | ", |meta| match meta {
+        let label = (prefix, self.locale.label(Label::Valid), ": ");
+        width += self.join(metas, label, "", " | ", |meta| match meta {
             Meta::Valid(value) => Some(Cow::Borrowed(value)),
             _ => None,
         })?;
         let prefix = if width > 0 { ", " } else { "" };
-        width += self.join(metas, (prefix, "default: "), "", " | ", |meta| match meta {
-            Meta::Default(value) => Some(Cow::Borrowed(value)),
+        let label = (prefix, self.locale.label(Label::Default), ": ");
+        width += self.join(metas, label, "", " | ", |meta| match meta {
+            Meta::Default(value) if !secret => Some(Cow::Borrowed(value)),
             Meta::Environment(value) => Some(Cow::Owned(format!("${value}"))),
             _ => None,
         })?;
@@ -380,16 +590,10 @@ impl<'a, S: Style + ?Sized + 'a> Helper<'a, S> {
         let mut helper = self.own();
         for meta in Meta::visible(metas) {
             match meta {
-                Meta::Help(value) => {
+                Meta::Details(_) | Meta::Note(_) if helper.brief => {}
+                Meta::Help(value) | Meta::Details(value) => {
                     helper.indentation()?;
-                    helper.wrap(
-                        value,
-                        helper.style.begin(Item::Help),
-                        helper.style.end(Item::Help),
-                        "",
-                        &mut 0,
-                        &mut false,
-                    )?;
+                    helper.text(value, Item::Help, "", &mut 0, &mut false)?;
                     helper.write_line("")?;
                 }
                 Meta::Line => {
@@ -397,19 +601,13 @@ impl<'a, S: Style + ?Sized + 'a> Helper<'a, S> {
                 }
                 Meta::Note(value) => {
                     helper.indentation()?;
-                    helper.wrap(
-                        value,
-                        helper.style.begin(Item::Note),
-                        helper.style.end(Item::Note),
-                        "",
-                        &mut 0,
-                        &mut false,
-                    )?;
+                    helper.text(value, Item::Note, "", &mut 0, &mut false)?;
                     helper.write_line("")?;
                 }
                 Meta::Group(metas) | Meta::Verb(metas) if depth == 0 => {
                     helper.write_header(root, metas)?;
                     helper.node(root, metas, depth + 1)?;
+                    helper.examples(metas)?;
                 }
                 Meta::Group(metas) => {
                     helper.indentation()?;
@@ -430,27 +628,29 @@ impl<'a, S: Style + ?Sized + 'a> Helper<'a, S> {
                 }
                 Meta::Verb(metas) => {
                     helper.indentation()?;
-                    let mut helper = helper.write_columns(metas, &columns, true)?;
-                    helper.summary(
-                        metas,
-                        helper.style.begin(Item::Summary),
-                        helper.style.end(Item::Summary),
-                        helper.indent,
-                    )?;
+                    let mut helper = helper.write_row(metas, &columns, true)?;
+                    let mut width = helper.indent;
+                    helper.summary(metas, Item::Summary, &mut width)?;
                     helper.write_line("")?;
                 }
                 Meta::Option(metas) => {
                     helper.indentation()?;
-                    let mut helper = helper.write_columns(metas, &columns, false)?;
+                    let mut helper = helper.write_row(metas, &columns, false)?;
                     let mut width = helper.indent;
-                    width += helper.summary(
-                        metas,
-                        helper.style.begin(Item::Summary),
-                        helper.style.end(Item::Summary),
-                        width,
-                    )?;
+                    helper.summary(metas, Item::Summary, &mut width)?;
+                    if helper.brief {
+                        helper.write_line("")?;
+                        continue;
+                    }
+                    for meta in Meta::visible(metas) {
+                        if let Meta::Details(value) = meta {
+                            helper.write_line("")?;
+                            width = helper.indentation()?;
+                            helper.text(value, Item::Help, "", &mut width, &mut false)?;
+                        }
+                    }
                     let buffer = helper.scope(|mut helper| helper.tags(metas))?;
-                    if width + buffer.len() > helper.style.width() {
+                    if width + buffer.width() > helper.style.width() {
                         helper.write_line("")?;
                         width = helper.indentation()?;
                     } else if width > helper.indent {
@@ -472,6 +672,40 @@ impl<'a, S: Style + ?Sized + 'a> Helper<'a, S> {
         Ok(())
     }
 
+    fn examples(&mut self, metas: &[Meta]) -> fmt::Result {
+        if self.brief {
+            return Ok(());
+        }
+        let mut has = false;
+        for meta in Meta::visible(metas) {
+            if let Meta::Example(command, text) = meta {
+                if !replace(&mut has, true) {
+                    self.write_line(())?;
+                    self.indentation()?;
+                    self.write_line((
+                        self.style.begin(Item::Group),
+                        self.locale.examples(),
+                        self.style.end(Item::Group),
+                    ))?;
+                }
+                let mut helper = self.indent();
+                if !text.chars().all(char::is_whitespace) {
+                    let mut cursor = helper.indentation()?;
+                    helper.text(text, Item::Summary, "", &mut cursor, &mut false)?;
+                    helper.write_line(())?;
+                }
+                let mut helper = helper.indent();
+                helper.indentation()?;
+                helper.write_line((
+                    helper.style.begin(Item::Example),
+                    command.as_ref(),
+                    helper.style.end(Item::Example),
+                ))?;
+            }
+        }
+        Ok(())
+    }
+
     fn write_header(&mut self, root: &Meta, metas: &[Meta]) -> Result<usize, fmt::Error> {
         let mut width = 0;
         width += self.write_line(())?;
@@ -505,24 +739,21 @@ impl<'a, S: Style + ?Sized + 'a> Helper<'a, S> {
         }
         let mut has = false;
         let buffer = self.scope(|mut helper| {
-            let line = (
-                helper.style.begin(Item::Bar(Line::Description)),
-                helper.style.end(Item::Bar(Line::Description)),
-                helper.style.begin(Item::Arrow(Line::Description)),
-                helper.style.end(Item::Arrow(Line::Description)),
-                ' ',
-                helper.style.begin(Item::Description),
-            );
+            let links = helper.style.hyperlinks();
             let count = helper.description(
                 metas,
-                line,
-                helper.style.end(Item::Description),
-                (helper.style.end(Item::Description), line),
+                (
+                    helper.style.begin(Item::Bar(Line::Description)),
+                    helper.style.end(Item::Bar(Line::Description)),
+                    helper.style.begin(Item::Arrow(Line::Description)),
+                    helper.style.end(Item::Arrow(Line::Description)),
+                    ' ',
+                ),
             )?;
             width += count;
             has |= count > 0;
 
-            let count = helper.join(
+            let count = helper.list(
                 metas,
                 (
                     '\n',
@@ -536,14 +767,14 @@ impl<'a, S: Style + ?Sized + 'a> Helper<'a, S> {
                 helper.style.end(Item::Link),
                 " ",
                 |meta| match meta {
-                    Meta::Home(value) => Some(Cow::Borrowed(value)),
+                    Meta::Home(value) => Some(Hyperlink(links.then_some(value.as_ref()), value)),
                     _ => None,
                 },
             )?;
             width += count;
             has |= count > 0;
 
-            let count = helper.join(
+            let count = helper.list(
                 metas,
                 (
                     '\n',
@@ -557,7 +788,9 @@ impl<'a, S: Style + ?Sized + 'a> Helper<'a, S> {
                 helper.style.end(Item::Link),
                 " ",
                 |meta| match meta {
-                    Meta::Repository(value) => Some(Cow::Borrowed(value)),
+                    Meta::Repository(value) => {
+                        Some(Hyperlink(links.then_some(value.as_ref()), value))
+                    }
                     _ => None,
                 },
             )?;
@@ -607,7 +840,7 @@ impl<'a, S: Style + ?Sized + 'a> Helper<'a, S> {
         metas: &[Meta],
         columns: &Columns,
         verb: bool,
-    ) -> Result<Helper<S>, fmt::Error> {
+    ) -> Result<Helper<'_, S>, fmt::Error> {
         let item = if verb { Item::Verb } else { Item::Option };
         let mut width = 0;
         let pad = self.style.indent();
@@ -639,6 +872,29 @@ impl<'a, S: Style + ?Sized + 'a> Helper<'a, S> {
         Ok(self.indent_with(width))
     }
 
+    fn write_row(
+        &mut self,
+        metas: &[Meta],
+        columns: &Columns,
+        verb: bool,
+    ) -> Result<Helper<'_, S>, fmt::Error> {
+        let pad = self.style.indent();
+        let width = [columns.short, columns.long, columns.types]
+            .into_iter()
+            .filter(|&width| width > 0)
+            .map(|width| width + pad)
+            .sum::<usize>();
+        if self.indent + width + STACK > self.style.width() && summary(metas).is_some() {
+            self.write_columns(metas, columns, verb)?;
+            self.write_line("")?;
+            let mut helper = self.indent();
+            helper.indentation()?;
+            Ok(helper)
+        } else {
+            self.write_columns(metas, columns, verb)
+        }
+    }
+
     #[inline]
     fn write(&mut self, value: impl Format) -> Result<usize, fmt::Error> {
         let width = value.width();
@@ -673,27 +929,386 @@ pub(crate) fn help<S: Style + ?Sized>(
     meta: &Meta,
     path: &[Key],
     style: &S,
+    locale: &dyn Locale,
+    brief: bool,
+    markup: bool,
 ) -> Option<String> {
     let mut buffer = String::new();
     let mut writer = Helper {
         buffer: &mut buffer,
         path,
         style,
+        locale,
+        brief,
+        markup,
         indent: 0,
     };
     writer.node(root, from_ref(meta), 0).ok()?;
     Some(buffer)
 }
 
+pub(crate) fn topic<S: Style + ?Sized>(
+    name: &str,
+    text: &str,
+    style: &S,
+    locale: &dyn Locale,
+    markup: bool,
+) -> Option<String> {
+    let mut buffer = String::new();
+    let mut writer = Helper {
+        buffer: &mut buffer,
+        path: &[],
+        style,
+        locale,
+        brief: false,
+        markup,
+        indent: 0,
+    };
+    writer.write_line(()).ok()?;
+    writer
+        .write_line((
+            style.begin(Item::Bar(Line::Head)),
+            style.end(Item::Bar(Line::Head)),
+            ' ',
+            style.begin(Item::Head),
+            name,
+            style.end(Item::Head),
+        ))
+        .ok()?;
+    writer.write_line(()).ok()?;
+    writer.text(text, Item::Help, "", &mut 0, &mut false).ok()?;
+    writer.write_line(()).ok()?;
+    Some(buffer)
+}
+
+pub(crate) fn search<S: Style + ?Sized>(
+    root: &Meta,
+    keyword: &str,
+    style: &S,
+    locale: &dyn Locale,
+    suggestions: (usize, usize),
+    markup: bool,
+) -> Option<String> {
+    fn options<'a>(metas: &'a [Meta], found: &mut Vec<&'a [Meta]>) {
+        for meta in Meta::visible(metas) {
+            match meta {
+                Meta::Option(metas) => found.push(metas),
+                Meta::Group(metas) => options(metas, found),
+                _ => {}
+            }
+        }
+    }
+
+    fn score(metas: &[Meta], keyword: &str, maximum: usize, spell: &mut Spell) -> Option<usize> {
+        let mut words = Vec::new();
+        for meta in Meta::visible(metas) {
+            match meta {
+                Meta::Name(_, value)
+                | Meta::Help(value)
+                | Meta::Details(value)
+                | Meta::Summary(value)
+                | Meta::Note(value) => {
+                    if value.to_lowercase().contains(keyword) {
+                        return Some(0);
+                    }
+                    words.extend(
+                        value
+                            .split(|letter: char| !letter.is_alphanumeric())
+                            .filter(|word| !word.is_empty()),
+                    );
+                }
+                _ => {}
+            }
+        }
+        let words = words.into_iter().map(|word| (word, 0));
+        let (_, distance) = spell.suggest(keyword, words, maximum).into_iter().next()?;
+        Some(distance + 1)
+    }
+
+    let keyword = keyword.to_lowercase();
+    let maximum = Spell::maximum(&keyword, suggestions);
+    let mut spell = Spell::new();
+    let mut results = Vec::new();
+    walk(root, &mut |path, meta| {
+        if path.len() > 1 {
+            if let Some(distance) = score(meta.children(), &keyword, maximum, &mut spell) {
+                let names = path.last().map(|key| key.to_string()).unwrap_or_default();
+                let path = title(&path[..path.len() - 1], " ");
+                results.push((distance, path, Item::Verb, names, summary(meta.children())));
+            }
+        }
+        let mut metas = Vec::new();
+        options(meta.children(), &mut metas);
+        for metas in metas {
+            if let Some(distance) = score(metas, &keyword, maximum, &mut spell) {
+                let names = Meta::visible(metas)
+                    .filter_map(|meta| match meta {
+                        Meta::Name(Name::Short | Name::Long, name) => Some(name.as_ref()),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                results.push((
+                    distance,
+                    title(path, " "),
+                    Item::Option,
+                    names,
+                    summary(metas),
+                ));
+            }
+        }
+        Ok(())
+    })
+    .ok()?;
+    results.sort_by_key(|&(distance, ..)| distance);
+
+    let mut buffer = String::new();
+    let mut writer = Helper {
+        buffer: &mut buffer,
+        path: &[],
+        style,
+        locale,
+        brief: false,
+        markup,
+        indent: 0,
+    };
+    writer.write_line(()).ok()?;
+    writer
+        .write_line((
+            style.begin(Item::Bar(Line::Head)),
+            style.end(Item::Bar(Line::Head)),
+            ' ',
+            style.begin(Item::Head),
+            locale.results(&keyword),
+            style.end(Item::Head),
+        ))
+        .ok()?;
+    writer.write_line(()).ok()?;
+    if results.is_empty() {
+        writer.write_line(locale.empty()).ok()?;
+        return Some(buffer);
+    }
+
+    let column = results
+        .iter()
+        .map(|(_, path, _, names, _)| path.width() + names.width() + 1)
+        .max()
+        .unwrap_or_default();
+    for (_, path, item, names, summary) in results {
+        let mut helper = writer.indent();
+        helper.indentation().ok()?;
+        let width = helper
+            .write((
+                style.begin(Item::Verb),
+                path.as_str(),
+                style.end(Item::Verb),
+                ' ',
+                style.begin(item),
+                names.as_str(),
+                style.end(item),
+            ))
+            .ok()?;
+        if let Some(summary) = summary {
+            let summary = summary.lines().next().unwrap_or_default();
+            helper.space(column.saturating_sub(width) + 2).ok()?;
+            helper
+                .write((
+                    style.begin(Item::Summary),
+                    summary,
+                    style.end(Item::Summary),
+                ))
+                .ok()?;
+        }
+        helper.write_line(()).ok()?;
+    }
+    Some(buffer)
+}
+
+pub(crate) fn find<'a>(metas: &'a [Meta], key: &str) -> Option<&'a Meta> {
+    Meta::visible(metas).find_map(|meta| match meta {
+        Meta::Option(metas) | Meta::Verb(metas) => Meta::visible(metas)
+            .any(|meta| matches!(meta, Meta::Name(_, name) if name == key))
+            .then_some(meta),
+        Meta::Topic(name, _) if name == key => Some(meta),
+        Meta::Group(metas) if name(metas, Name::Plain).is_some_and(|name| name == key) => {
+            Some(meta)
+        }
+        Meta::Group(metas) => find(metas, key),
+        _ => None,
+    })
+}
+
+pub(crate) fn synopsis(
+    root: &Meta,
+    meta: &Meta,
+    path: &[Key],
+    locale: &dyn Locale,
+) -> Option<String> {
+    let mut buffer = String::new();
+    let mut writer = Helper {
+        buffer: &mut buffer,
+        path,
+        style: &Plain,
+        locale,
+        brief: false,
+        markup: false,
+        indent: 0,
+    };
+    writer.path = path.get(1..).unwrap_or_default();
+    writer.synopsis(root, meta.children()).ok()?;
+    Some(buffer.trim_start().to_string())
+}
+
+pub(crate) fn tags(meta: &Meta, locale: &dyn Locale) -> Option<String> {
+    let mut buffer = String::new();
+    let mut writer = Helper {
+        buffer: &mut buffer,
+        path: &[],
+        style: &Plain,
+        locale,
+        brief: false,
+        markup: false,
+        indent: 0,
+    };
+    writer.tags(meta.children()).ok()?;
+    Some(buffer)
+}
+
+pub(crate) fn walk<'a>(
+    root: &'a Meta,
+    visit: &mut impl FnMut(&[Key], &'a Meta) -> fmt::Result,
+) -> fmt::Result {
+    fn descend<'a>(
+        meta: &'a Meta,
+        path: &mut Vec<Key>,
+        visit: &mut impl FnMut(&[Key], &'a Meta) -> fmt::Result,
+    ) -> fmt::Result {
+        visit(path, meta)?;
+        for verb in verbs(meta.children()) {
+            if let Some(name) = self::verb(verb) {
+                path.push(Key::Name(name.clone()));
+                descend(verb, path, visit)?;
+                path.pop();
+            }
+        }
+        Ok(())
+    }
+
+    let name = name(root.children(), Name::Plain)
+        .cloned()
+        .unwrap_or(Cow::Borrowed("command"));
+    descend(root, &mut vec![Key::Name(name)], visit)
+}
+
+pub(crate) fn owner(root: &Meta, key: &str, path: &[Key]) -> Option<Vec<Key>> {
+    fn has(metas: &[Meta], key: &str) -> bool {
+        Meta::visible(metas).any(|meta| match meta {
+            Meta::Option(metas) => Meta::visible(metas).any(
+                |meta| matches!(meta, Meta::Name(Name::Short | Name::Long, name) if name == key),
+            ),
+            Meta::Group(metas) => has(metas, key),
+            _ => false,
+        })
+    }
+
+    let mut nearest = None::<(usize, Vec<Key>)>;
+    walk(root, &mut |keys, meta| {
+        let verbs = keys.get(1..).unwrap_or_default();
+        if verbs != path && has(meta.children(), key) {
+            let common = verbs
+                .iter()
+                .zip(path)
+                .take_while(|(left, right)| left == right);
+            let distance = verbs.len() + path.len() - 2 * common.count();
+            if nearest.as_ref().map_or(true, |pair| distance < pair.0) {
+                nearest = Some((distance, keys.to_vec()));
+            }
+        }
+        Ok(())
+    })
+    .ok()?;
+    nearest.map(|pair| pair.1)
+}
+
+pub(crate) fn verbs(metas: &[Meta]) -> Vec<&Meta> {
+    fn descend<'a>(metas: &'a [Meta], verbs: &mut Vec<&'a Meta>) {
+        for meta in Meta::visible(metas) {
+            match meta {
+                Meta::Verb(_) => verbs.push(meta),
+                Meta::Group(metas) => descend(metas, verbs),
+                _ => {}
+            }
+        }
+    }
+
+    let mut verbs = Vec::new();
+    descend(metas, &mut verbs);
+    verbs
+}
+
+pub(crate) fn verb(meta: &Meta) -> Option<&Cow<'static, str>> {
+    name(meta.children(), Name::Long).or_else(|| name(meta.children(), Name::Short))
+}
+
+pub(crate) fn name(metas: &[Meta], kind: Name) -> Option<&Cow<'static, str>> {
+    Meta::visible(metas).find_map(|meta| match meta {
+        Meta::Name(name, value) if *name == kind => Some(value),
+        _ => None,
+    })
+}
+
+pub(crate) fn title(path: &[Key], separator: &str) -> String {
+    let mut buffer = String::new();
+    for key in path {
+        if !buffer.is_empty() {
+            buffer.push_str(separator);
+        }
+        let _ = write!(buffer, "{key}");
+    }
+    buffer
+}
+
+pub(crate) fn summary(metas: &[Meta]) -> Option<&str> {
+    let mut help = None;
+    for meta in Meta::visible(metas) {
+        match meta {
+            Meta::Summary(value) => return Some(value),
+            Meta::Help(value) => help = help.or(Some(value)),
+            _ => {}
+        }
+    }
+    help.map(|value| value.as_ref())
+}
+
+pub(crate) fn kind(metas: &[Meta]) -> Option<&Cow<'static, str>> {
+    Meta::visible(metas).fold(None, |kind, meta| match meta {
+        Meta::Type(value) => Some(value),
+        _ => kind,
+    })
+}
+
 pub(crate) fn version(meta: &Meta, depth: usize) -> Option<String> {
-    join(meta, depth, |meta| match meta {
+    join(meta, depth, ", ", |meta| match meta {
         Meta::Version(version) => Some(Cow::Borrowed(version)),
         _ => None,
     })
 }
 
+pub(crate) fn verbose(meta: &Meta, depth: usize) -> Option<String> {
+    let version = version(meta, depth)?;
+    let metadata = join(meta, depth, "\n", |meta| match meta {
+        Meta::Metadata(key, value) => Some(Cow::Owned(format!("{key}: {value}"))),
+        _ => None,
+    })?;
+    match (version.is_empty(), metadata.is_empty()) {
+        (_, true) => Some(version),
+        (true, false) => Some(metadata),
+        (false, false) => Some(format!("{version}\n{metadata}")),
+    }
+}
+
 pub(crate) fn license(meta: &Meta, depth: usize) -> Option<String> {
-    join(meta, depth, |meta| match meta {
+    join(meta, depth, ", ", |meta| match meta {
         Meta::License(name, file) => match fs::read_to_string(file.deref()) {
             Ok(content) => Some(Cow::Owned(content)),
             Err(_) if file.chars().all(char::is_whitespace) => Some(Cow::Borrowed(name)),
@@ -704,28 +1319,93 @@ pub(crate) fn license(meta: &Meta, depth: usize) -> Option<String> {
 }
 
 pub(crate) fn author(meta: &Meta, depth: usize) -> Option<String> {
-    join(meta, depth, |meta| match meta {
+    join(meta, depth, ", ", |meta| match meta {
         Meta::Author(author) => Some(Cow::Borrowed(author)),
         _ => None,
     })
 }
 
-fn join(meta: &Meta, depth: usize, find: impl Fn(&Meta) -> Option<Cow<str>>) -> Option<String> {
+pub(crate) fn spans(line: &str, markup: bool) -> Vec<(Option<Item>, &str)> {
+    if !markup {
+        return vec![(None, line)];
+    }
+    let mut spans = Vec::new();
+    let (mut start, mut index) = (0, 0);
+    while index < line.len() {
+        let rest = &line[index..];
+        if rest.starts_with("\\*") || rest.starts_with("\\`") {
+            if start < index {
+                spans.push((None, &line[start..index]));
+            }
+            start = index + 1;
+            index += 2;
+            continue;
+        }
+        let open = !line[..index]
+            .chars()
+            .next_back()
+            .is_some_and(char::is_alphanumeric);
+        let found = [
+            ("`", Item::Code),
+            ("**", Item::Strong),
+            ("*", Item::Emphasis),
+        ]
+        .into_iter()
+        .find_map(|(mark, item)| {
+            let inner = rest.strip_prefix(mark)?;
+            let code = matches!(item, Item::Code);
+            if !code && !open {
+                return None;
+            }
+            let text = inner
+                .match_indices(mark)
+                .map(|(end, _)| &inner[..end])
+                .find(|text| {
+                    code || !inner[text.len() + mark.len()..].starts_with(char::is_alphanumeric)
+                })?;
+            let valid = !text.is_empty() && !text.starts_with(' ') && !text.ends_with(' ');
+            valid.then_some((item, text, text.len() + mark.len() * 2))
+        });
+        match found {
+            Some((item, text, length)) => {
+                if start < index {
+                    spans.push((None, &line[start..index]));
+                }
+                spans.push((Some(item), text));
+                index += length;
+                start = index;
+            }
+            None => index += rest.chars().next().map_or(1, char::len_utf8),
+        }
+    }
+    if start < line.len() {
+        spans.push((None, &line[start..]));
+    }
+    spans
+}
+
+fn join(
+    meta: &Meta,
+    depth: usize,
+    separator: &str,
+    find: impl Fn(&Meta) -> Option<Cow<str>>,
+) -> Option<String> {
     fn descend(
         meta: &Meta,
         depth: usize,
+        separator: &str,
         buffer: &mut String,
         find: impl Fn(&Meta) -> Option<Cow<str>> + Copy,
     ) -> fmt::Result {
         match meta {
             Meta::Option(metas) | Meta::Verb(metas) | Meta::Group(metas) if depth > 0 => {
                 for meta in metas {
-                    descend(meta, depth - 1, buffer, find)?;
+                    descend(meta, depth - 1, separator, buffer, find)?;
                 }
             }
             meta => match find(meta) {
                 Some(value) if buffer.is_empty() => write!(buffer, "{value}")?,
-                Some(value) => write!(buffer, ", {value}")?,
+                Some(value) => write!(buffer, "{separator}{value}")?,
                 None => {}
             },
         }
@@ -733,6 +1413,6 @@ fn join(meta: &Meta, depth: usize, find: impl Fn(&Meta) -> Option<Cow<str>>) ->
     }
 
     let mut buffer = String::new();
-    descend(meta, depth, &mut buffer, &find).ok()?;
+    descend(meta, depth, separator, &mut buffer, &find).ok()?;
     Some(buffer)
 }
@@ -1,8 +1,14 @@
 pub mod build;
 mod case;
+pub mod describe;
+mod doc;
 mod error;
 mod help;
+pub mod locale;
+mod man;
 pub mod meta;
+pub mod metadata;
+mod page;
 pub mod parse;
 pub mod scope;
 mod spell;
@@ -24,22 +30,41 @@ pub use crate::{
     - A 'Plain' style for minimal formatting.
     - Ability to implement a custom style.
     - Nearest suggestions on typos.
+    - Dynamic completion of arguments with custom value completers.
+    - Man page generation.
+    - Markdown and HTML documentation export.
+    - Machine-readable description of the command line interface.
+    - Automatic usage synthesis.
+    - Contextual help for verbs, options, groups and topics.
+    - Keyword search across help.
+    - Customizable built-in options.
+    - Localization of built-in messages and errors.
+    - Themes for the `Termion` style.
+    - Color depth detection.
+    - Automatic color choice with a '--color' option.
+    - Unicode-aware help layout.
+    - Responsive help layout.
+    - Paged help.
+    - Validated examples.
+    - Compact and full help.
+    - Long version output with build metadata.
+    - Lightweight markup in help text.
+    - Clickable hyperlinks in help.
+    - Weighted and configurable typo suggestions.
+    - Suggestions for arguments given at the wrong level.
+    - Interactive prompting for missing values.
+    - Secret options.
+    - Option values from files or stdin.
 
     TODO:
     - Favor `Deserialize` over `FromStr`.
         - Define a 'MetaDeserializer' which will be used to collect meta data from a type `T: Deserialize` (including variant names and more).
         - For enums, build a map between case-converted keys and variant names.
-    - Generate usage string automatically.
-        - Usage: {verb (for root use the root name)} [position options (if any)] [named options (if any)] {sub-command (if any)}
     - Ensure that variables don't obscure the context variable.
     - Support for streamed arguments via stdin, file system, http.
-    - Support for a value with --help
-        - Allows to provide a help context when help becomes very large (ex: --help branch)
-    - Autocomplete?
     - Simplify the 'Into<Cow<'static, str>>' all over the place, if possible.
         - There are probably some places where the `Cow` isn't useful.
     - Can I unify 'Builder' and 'Parser'?
-    - Allow to rename '--help' and '--version'?
     - Support for json values.
 */
 
@@ -48,6 +73,15 @@ const VERSION: usize = usize::MAX - 1;
 const LICENSE: usize = usize::MAX - 2;
 const AUTHOR: usize = usize::MAX - 3;
 const BREAK: usize = usize::MAX - 4;
+const SEARCH: usize = usize::MAX - 5;
+const COLOR: usize = usize::MAX - 6;
+const BRIEF: usize = usize::MAX - 7;
+const VERBOSE: usize = usize::MAX - 8;
+const COMPLETE: &str = "SCALP_COMPLETE";
+const THEME: &str = "SCALP_THEME";
+const COLUMNS: &str = "COLUMNS";
+const WIDTH: usize = 40;
+const LIMIT: u64 = 1 << 20;
 
 const SHIFT: u32 = 5;
 const MASK: usize = (1 << SHIFT) - 1;
@@ -77,3 +111,34 @@ macro_rules! header {
         })
     };
 }
+
+#[macro_export]
+macro_rules! metadata {
+    () => {
+        |builder: $crate::Builder<$crate::scope::Root, _>| $crate::metadata!(builder)
+    };
+    ($builder: expr) => {
+        $builder.pipe(|builder| {
+            [
+                ("commit", option_env!("SCALP_COMMIT")),
+                ("date", option_env!("SCALP_DATE")),
+                ("target", option_env!("SCALP_TARGET")),
+                ("rustc", option_env!("SCALP_RUSTC")),
+                ("features", option_env!("SCALP_FEATURES")),
+            ]
+            .into_iter()
+            .fold(builder, |builder, (key, value)| match value {
+                Some(value) => builder.metadata(key, value.trim()),
+                None => builder,
+            })
+        })
+    };
+}
+
+const _: () = assert!(
+    metadata::equals(metadata::COMMIT, "SCALP_COMMIT")
+        && metadata::equals(metadata::DATE, "SCALP_DATE")
+        && metadata::equals(metadata::TARGET, "SCALP_TARGET")
+        && metadata::equals(metadata::RUSTC, "SCALP_RUSTC")
+        && metadata::equals(metadata::FEATURES, "SCALP_FEATURES")
+);
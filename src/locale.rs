@@ -0,0 +1,125 @@
+use crate::{error::Error, meta::Builtin};
+use core::fmt;
+use std::borrow::Cow;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Heading {
+    Name,
+    Synopsis,
+    Description,
+    Options,
+    Commands,
+    Examples,
+    Environment,
+    Authors,
+    License,
+    Links,
+    Option,
+    Command,
+    Type,
+    Default,
+    Valid,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Label {
+    Options,
+    Command,
+    Default,
+    Valid,
+}
+
+pub trait Locale {
+    fn language(&self) -> &str;
+
+    fn help(&self, builtin: Builtin) -> Cow<'static, str> {
+        Cow::Borrowed(match builtin {
+            Builtin::Author => "Displays author information.",
+            Builtin::License => "Displays license information.",
+            Builtin::Version => "Displays version information.",
+            Builtin::Verbose => "Adds build metadata to the version information.",
+            Builtin::Help => "Displays this help message.",
+            Builtin::Search => "Searches the help of every option and command for a keyword.",
+            Builtin::Color => "Chooses when to color the output (auto, always or never).",
+        })
+    }
+
+    fn usage(&self) -> Cow<'static, str> {
+        Cow::Borrowed("Usage:")
+    }
+
+    fn examples(&self) -> Cow<'static, str> {
+        Cow::Borrowed("Examples:")
+    }
+
+    fn heading(&self, heading: Heading) -> Cow<'static, str> {
+        Cow::Borrowed(match heading {
+            Heading::Name => "Name",
+            Heading::Synopsis => "Synopsis",
+            Heading::Description => "Description",
+            Heading::Options => "Options",
+            Heading::Commands => "Commands",
+            Heading::Examples => "Examples",
+            Heading::Environment => "Environment",
+            Heading::Authors => "Authors",
+            Heading::License => "License",
+            Heading::Links => "See Also",
+            Heading::Option => "Option",
+            Heading::Command => "Command",
+            Heading::Type => "Type",
+            Heading::Default => "Default",
+            Heading::Valid => "Valid",
+        })
+    }
+
+    fn label(&self, label: Label) -> Cow<'static, str> {
+        Cow::Borrowed(match label {
+            Label::Options => "OPTIONS",
+            Label::Command => "COMMAND",
+            Label::Default => "default",
+            Label::Valid => "valid",
+        })
+    }
+
+    fn results(&self, keyword: &str) -> Cow<'static, str> {
+        Cow::Owned(format!("Results for '{keyword}'"))
+    }
+
+    fn empty(&self) -> Cow<'static, str> {
+        Cow::Borrowed("No matches.")
+    }
+
+    fn error(&self, error: &Error, formatter: &mut fmt::Formatter) -> fmt::Result {
+        error.english(formatter)
+    }
+}
+
+pub struct English;
+
+pub struct Localized<'a>(pub(crate) &'a Error, pub(crate) &'a dyn Locale);
+
+impl Locale for English {
+    fn language(&self) -> &str {
+        "en"
+    }
+}
+
+impl fmt::Display for Localized<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.1.error(self.0, f)
+    }
+}
+
+pub(crate) fn language(environment: impl Fn(&str) -> Option<String>) -> Option<String> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(environment)
+        .find(|value| !value.is_empty())
+        .map(|value| {
+            value
+                .split(['_', '-', '.', '@'])
+                .next()
+                .unwrap_or_default()
+                .to_lowercase()
+        })
+}
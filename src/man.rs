@@ -0,0 +1,373 @@
+use crate::{
+    help,
+    locale::{Heading, Locale},
+    meta::{Meta, Name},
+    parse::Key,
+    style::Item,
+};
+use core::{
+    fmt::{self, Write},
+    mem::replace,
+};
+
+struct Page<'a> {
+    buffer: &'a mut String,
+    root: &'a Meta,
+    path: &'a [Key],
+    locale: &'a dyn Locale,
+    markup: bool,
+}
+
+impl Page<'_> {
+    fn write(&mut self, meta: &Meta) -> fmt::Result {
+        let title = help::title(self.path, "-");
+        write!(self.buffer, ".TH \"")?;
+        self.escape(&title.to_uppercase())?;
+        write!(self.buffer, "\" \"1\" \"\" \"")?;
+        self.escape(&help::title(&self.path[..1], ""))?;
+        if let Some(version) = help::version(self.root, 1).filter(|value| !value.is_empty()) {
+            write!(self.buffer, " ")?;
+            self.escape(&version)?;
+        }
+        writeln!(self.buffer, "\"")?;
+
+        self.name(&title, meta.children())?;
+        self.synopsis(meta)?;
+        self.description(meta.children())?;
+        self.options(meta.children())?;
+        self.commands(meta.children())?;
+        self.environment(meta.children())?;
+        self.examples(meta.children())?;
+        self.authors()?;
+        self.license()?;
+        self.links()
+    }
+
+    fn name(&mut self, title: &str, metas: &[Meta]) -> fmt::Result {
+        self.section(Heading::Name)?;
+        self.escape(title)?;
+        if let Some(summary) = help::summary(metas) {
+            write!(self.buffer, " \\- ")?;
+            self.text(summary.lines().next().unwrap_or_default())?;
+        }
+        writeln!(self.buffer)
+    }
+
+    fn synopsis(&mut self, meta: &Meta) -> fmt::Result {
+        self.section(Heading::Synopsis)?;
+        let mut has = false;
+        for meta in Meta::visible(meta.children()) {
+            if let Meta::Usage(value) = meta {
+                if has {
+                    writeln!(self.buffer, ".br")?;
+                }
+                self.escape(value)?;
+                writeln!(self.buffer)?;
+                has = true;
+            }
+        }
+        if !has {
+            let synopsis =
+                help::synopsis(self.root, meta, self.path, self.locale).ok_or(fmt::Error)?;
+            self.escape(&synopsis)?;
+            writeln!(self.buffer)?;
+        }
+        Ok(())
+    }
+
+    fn description(&mut self, metas: &[Meta]) -> fmt::Result {
+        let mut has = false;
+        for meta in Meta::visible(metas) {
+            if let Meta::Help(value) | Meta::Details(value) | Meta::Note(value) = meta {
+                if !has {
+                    self.section(Heading::Description)?;
+                    has = true;
+                }
+                writeln!(self.buffer, ".PP")?;
+                self.text(value)?;
+                writeln!(self.buffer)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn options(&mut self, metas: &[Meta]) -> fmt::Result {
+        fn has(metas: &[Meta]) -> bool {
+            Meta::visible(metas).any(|meta| match meta {
+                Meta::Option(_) => true,
+                Meta::Group(metas) => has(metas),
+                _ => false,
+            })
+        }
+
+        if has(metas) {
+            self.section(Heading::Options)?;
+            self.group(metas)?;
+        }
+        Ok(())
+    }
+
+    fn group(&mut self, metas: &[Meta]) -> fmt::Result {
+        for meta in Meta::visible(metas) {
+            match meta {
+                Meta::Option(metas) => self.option(meta, metas)?,
+                Meta::Group(metas) => {
+                    if let Some(name) = help::name(metas, Name::Plain) {
+                        write!(self.buffer, ".SS ")?;
+                        self.escape(name)?;
+                        writeln!(self.buffer)?;
+                    }
+                    self.group(metas)?;
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn option(&mut self, meta: &Meta, metas: &[Meta]) -> fmt::Result {
+        writeln!(self.buffer, ".TP")?;
+        let mut has = false;
+        for meta in Meta::visible(metas) {
+            if let Meta::Name(Name::Short | Name::Long, value) = meta {
+                if has {
+                    write!(self.buffer, ", ")?;
+                }
+                write!(self.buffer, "\\fB")?;
+                self.escape(value)?;
+                write!(self.buffer, "\\fR")?;
+                has = true;
+            }
+        }
+        if let Some(kind) = help::kind(metas) {
+            if has {
+                write!(self.buffer, " ")?;
+            }
+            write!(self.buffer, "<\\fI")?;
+            self.escape(kind)?;
+            write!(self.buffer, "\\fR>")?;
+        }
+        writeln!(self.buffer)?;
+        if let Some(summary) = help::summary(metas) {
+            self.text(summary)?;
+            writeln!(self.buffer)?;
+        }
+        match help::tags(meta, self.locale) {
+            Some(tags) if !tags.is_empty() => {
+                writeln!(self.buffer, ".br")?;
+                write!(self.buffer, "[")?;
+                self.escape(&tags)?;
+                writeln!(self.buffer, "]")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn commands(&mut self, metas: &[Meta]) -> fmt::Result {
+        let verbs = help::verbs(metas);
+        if verbs.is_empty() {
+            return Ok(());
+        }
+
+        self.section(Heading::Commands)?;
+        for verb in verbs {
+            let Some(name) = help::verb(verb) else {
+                continue;
+            };
+            writeln!(self.buffer, ".TP")?;
+            write!(self.buffer, "\\fB")?;
+            self.escape(name)?;
+            writeln!(self.buffer, "\\fR")?;
+            if let Some(summary) = help::summary(verb.children()) {
+                self.text(summary)?;
+                writeln!(self.buffer)?;
+            }
+            write!(self.buffer, "See \\fB")?;
+            self.escape(&help::title(self.path, "-"))?;
+            write!(self.buffer, "\\-")?;
+            self.escape(name)?;
+            writeln!(self.buffer, "\\fR(1).")?;
+        }
+        Ok(())
+    }
+
+    fn examples(&mut self, metas: &[Meta]) -> fmt::Result {
+        let mut has = false;
+        for meta in Meta::visible(metas) {
+            if let Meta::Example(command, text) = meta {
+                if !has {
+                    self.section(Heading::Examples)?;
+                    has = true;
+                }
+                writeln!(self.buffer, ".TP")?;
+                write!(self.buffer, "\\fB")?;
+                self.escape(command)?;
+                writeln!(self.buffer, "\\fR")?;
+                self.text(text)?;
+                writeln!(self.buffer)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn environment(&mut self, metas: &[Meta]) -> fmt::Result {
+        fn variables<'a>(metas: &'a [Meta], pairs: &mut Vec<(&'a str, &'a [Meta])>) {
+            for meta in Meta::visible(metas) {
+                match meta {
+                    Meta::Option(options) => {
+                        for meta in Meta::visible(options) {
+                            if let Meta::Environment(variable) = meta {
+                                pairs.push((variable, options));
+                            }
+                        }
+                    }
+                    Meta::Group(metas) => variables(metas, pairs),
+                    _ => {}
+                }
+            }
+        }
+
+        let mut pairs = Vec::new();
+        variables(metas, &mut pairs);
+        if pairs.is_empty() {
+            return Ok(());
+        }
+
+        self.section(Heading::Environment)?;
+        for (variable, metas) in pairs {
+            writeln!(self.buffer, ".TP")?;
+            write!(self.buffer, "\\fB")?;
+            self.escape(variable)?;
+            writeln!(self.buffer, "\\fR")?;
+            if let Some(name) =
+                help::name(metas, Name::Long).or_else(|| help::name(metas, Name::Short))
+            {
+                write!(self.buffer, "Fallback for \\fB")?;
+                self.escape(name)?;
+                writeln!(self.buffer, "\\fR.")?;
+            }
+            if let Some(summary) = help::summary(metas) {
+                self.text(summary)?;
+                writeln!(self.buffer)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn authors(&mut self) -> fmt::Result {
+        match help::author(self.root, 1) {
+            Some(authors) if !authors.is_empty() => {
+                self.section(Heading::Authors)?;
+                self.escape(&authors)?;
+                writeln!(self.buffer)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn license(&mut self) -> fmt::Result {
+        let mut has = false;
+        for meta in Meta::visible(self.root.children()) {
+            if let Meta::License(name, _) = meta {
+                if name.chars().all(char::is_whitespace) {
+                    continue;
+                }
+                if !has {
+                    self.section(Heading::License)?;
+                    has = true;
+                }
+                self.escape(name)?;
+                writeln!(self.buffer)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn links(&mut self) -> fmt::Result {
+        let mut has = false;
+        for meta in Meta::visible(self.root.children()) {
+            if let Meta::Home(value) | Meta::Repository(value) = meta {
+                if !has {
+                    self.section(Heading::Links)?;
+                    has = true;
+                } else {
+                    writeln!(self.buffer, ".br")?;
+                }
+                self.escape(value)?;
+                writeln!(self.buffer)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn section(&mut self, heading: Heading) -> fmt::Result {
+        let heading = self.locale.heading(heading).to_uppercase();
+        write!(self.buffer, ".SH ")?;
+        self.escape(&heading)?;
+        writeln!(self.buffer)
+    }
+
+    fn text(&mut self, value: &str) -> fmt::Result {
+        self.render(value, self.markup)
+    }
+
+    fn escape(&mut self, value: &str) -> fmt::Result {
+        self.render(value, false)
+    }
+
+    fn render(&mut self, value: &str, markup: bool) -> fmt::Result {
+        let mut has = false;
+        for line in value.split('\n') {
+            if markup && line.trim() == "```" {
+                continue;
+            }
+            if replace(&mut has, true) {
+                writeln!(self.buffer)?;
+            }
+            if line.starts_with(['.', '\'']) {
+                write!(self.buffer, "\\&")?;
+            }
+            for (span, text) in help::spans(line, markup) {
+                let font = match span {
+                    Some(Item::Emphasis) => "\\fI",
+                    Some(_) => "\\fB",
+                    None => "",
+                };
+                write!(self.buffer, "{font}")?;
+                for letter in text.chars() {
+                    match letter {
+                        '\\' => write!(self.buffer, "\\e")?,
+                        '-' => write!(self.buffer, "\\-")?,
+                        letter => self.buffer.write_char(letter)?,
+                    }
+                }
+                if span.is_some() {
+                    write!(self.buffer, "\\fR")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn pages(
+    root: &Meta,
+    locale: &dyn Locale,
+    markup: bool,
+) -> Result<Vec<(String, String)>, fmt::Error> {
+    let mut pages = Vec::new();
+    help::walk(root, &mut |path, meta| {
+        let mut buffer = String::new();
+        Page {
+            buffer: &mut buffer,
+            root,
+            path,
+            locale,
+            markup,
+        }
+        .write(meta)?;
+        pages.push((format!("{}.1", help::title(path, "-")), buffer));
+        Ok(())
+    })?;
+    Ok(pages)
+}
@@ -14,16 +14,21 @@ pub enum Meta {
     Name(Name, Cow<'static, str>),
     Position(usize),
     Version(Cow<'static, str>),
+    Metadata(Cow<'static, str>, Cow<'static, str>),
     License(Cow<'static, str>, Cow<'static, str>),
     Author(Cow<'static, str>),
     Help(Cow<'static, str>),
+    Details(Cow<'static, str>),
     Line,
     Usage(Cow<'static, str>),
     Summary(Cow<'static, str>),
     Home(Cow<'static, str>),
     Repository(Cow<'static, str>),
     Note(Cow<'static, str>),
+    Topic(Cow<'static, str>, Cow<'static, str>),
+    Example(Cow<'static, str>, Cow<'static, str>),
     Type(Cow<'static, str>),
+    Value(Cow<'static, str>),
     Valid(Cow<'static, str>),
     Require(Cow<'static, str>),
     Many(Option<NonZeroUsize>),
@@ -32,18 +37,35 @@ pub enum Meta {
     Show,
     Hide,
     Swizzle,
+    Secret,
+    Weight(usize),
     Option(Vec<Meta>),
     Options(Options),
+    Builtin(Builtin, Vec<Meta>),
     Verb(Vec<Meta>),
     Group(Vec<Meta>),
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Builtin {
+    Author,
+    License,
+    Version,
+    Verbose,
+    Help,
+    Search,
+    Color,
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Options {
     Author { short: bool, long: bool },
     License { short: bool, long: bool },
     Version { short: bool, long: bool },
+    Verbose { short: bool, long: bool },
     Help { short: bool, long: bool },
+    Search { short: bool, long: bool },
+    Color { short: bool, long: bool },
 }
 
 impl Options {
@@ -69,10 +91,22 @@ impl Options {
         Self::Version { short, long }
     }
 
+    pub const fn verbose(short: bool, long: bool) -> Self {
+        Self::Verbose { short, long }
+    }
+
     pub const fn help(short: bool, long: bool) -> Self {
         Self::Help { short, long }
     }
 
+    pub const fn search(short: bool, long: bool) -> Self {
+        Self::Search { short, long }
+    }
+
+    pub const fn color(short: bool, long: bool) -> Self {
+        Self::Color { short, long }
+    }
+
     pub const fn author(short: bool, long: bool) -> Self {
         Self::Author { short, long }
     }
@@ -80,6 +114,34 @@ impl Options {
     pub const fn license(short: bool, long: bool) -> Self {
         Self::License { short, long }
     }
+
+    pub const fn builtin(&self) -> Builtin {
+        match self {
+            Options::Author { .. } => Builtin::Author,
+            Options::License { .. } => Builtin::License,
+            Options::Version { .. } => Builtin::Version,
+            Options::Verbose { .. } => Builtin::Verbose,
+            Options::Help { .. } => Builtin::Help,
+            Options::Search { .. } => Builtin::Search,
+            Options::Color { .. } => Builtin::Color,
+        }
+    }
+
+    pub(crate) const fn names(&self) -> [Option<&'static str>; 2] {
+        let (short, long, names) = match *self {
+            Options::Author { short, long } => (short, long, ("a", "author")),
+            Options::License { short, long } => (short, long, ("l", "license")),
+            Options::Version { short, long } => (short, long, ("v", "version")),
+            Options::Verbose { short, long } => (short, long, ("V", "verbose")),
+            Options::Help { short, long } => (short, long, ("h", "help")),
+            Options::Search { short, long } => (short, long, ("S", "help-search")),
+            Options::Color { short, long } => (short, long, ("C", "color")),
+        };
+        [
+            if short { Some(names.0) } else { None },
+            if long { Some(names.1) } else { None },
+        ]
+    }
 }
 
 impl Meta {
@@ -88,16 +150,21 @@ impl Meta {
             Meta::Name(name, value) => Meta::Name(*name, value.clone()),
             Meta::Position(value) => Meta::Position(*value),
             Meta::Version(value) => Meta::Version(value.clone()),
+            Meta::Metadata(key, value) => Meta::Metadata(key.clone(), value.clone()),
             Meta::License(name, content) => Meta::License(name.clone(), content.clone()),
             Meta::Author(value) => Meta::Author(value.clone()),
             Meta::Help(value) => Meta::Help(value.clone()),
+            Meta::Details(value) => Meta::Details(value.clone()),
             Meta::Line => Meta::Line,
             Meta::Summary(value) => Meta::Summary(value.clone()),
             Meta::Home(value) => Meta::Home(value.clone()),
             Meta::Repository(value) => Meta::Repository(value.clone()),
             Meta::Usage(value) => Meta::Usage(value.clone()),
             Meta::Note(value) => Meta::Note(value.clone()),
+            Meta::Topic(name, text) => Meta::Topic(name.clone(), text.clone()),
+            Meta::Example(command, text) => Meta::Example(command.clone(), text.clone()),
             Meta::Type(value) => Meta::Type(value.clone()),
+            Meta::Value(value) => Meta::Value(value.clone()),
             Meta::Require(value) => Meta::Require(value.clone()),
             Meta::Many(value) => Meta::Many(*value),
             Meta::Default(value) => Meta::Default(value.clone()),
@@ -106,11 +173,17 @@ impl Meta {
             Meta::Hide => Meta::Hide,
             Meta::Show => Meta::Show,
             Meta::Swizzle => Meta::Swizzle,
+            Meta::Secret => Meta::Secret,
+            Meta::Weight(value) => Meta::Weight(*value),
             Meta::Option(metas) if depth > 0 => {
                 Meta::Option(metas.iter().map(|meta| meta.clone(depth - 1)).collect())
             }
             Meta::Option(_) => Meta::Option(Vec::new()),
             Meta::Options(options) => Meta::Options(*options),
+            Meta::Builtin(builtin, metas) => Meta::Builtin(
+                *builtin,
+                metas.iter().map(|meta| meta.clone(depth)).collect(),
+            ),
             Meta::Verb(metas) if depth > 0 => {
                 Meta::Verb(metas.iter().map(|meta| meta.clone(depth - 1)).collect())
             }
@@ -205,6 +278,10 @@ impl Meta {
         }
     }
 
+    pub(crate) fn secret(metas: &[Meta]) -> bool {
+        metas.iter().any(|meta| matches!(meta, Meta::Secret))
+    }
+
     pub(crate) fn visible<'a>(
         metas: impl IntoIterator<Item = &'a Meta>,
     ) -> impl Iterator<Item = &'a Meta> {
@@ -0,0 +1,137 @@
+use std::{
+    collections::HashMap,
+    env,
+    path::{Path, PathBuf},
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+pub const COMMIT: &str = "SCALP_COMMIT";
+pub const DATE: &str = "SCALP_DATE";
+pub const TARGET: &str = "SCALP_TARGET";
+pub const RUSTC: &str = "SCALP_RUSTC";
+pub const FEATURES: &str = "SCALP_FEATURES";
+
+pub fn emit() {
+    let directory = env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".into());
+    for path in watch(Path::new(&directory)) {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+    for (key, value) in collect(env::vars()) {
+        println!("cargo:rustc-env={key}={value}");
+    }
+}
+
+pub fn collect(
+    environment: impl IntoIterator<Item = (String, String)>,
+) -> Vec<(&'static str, String)> {
+    let environment: HashMap<_, _> = environment.into_iter().collect();
+    let directory = environment
+        .get("CARGO_MANIFEST_DIR")
+        .map_or(".", String::as_str);
+    let mut pairs = Vec::new();
+    if let Some(commit) = commit(Path::new(directory)) {
+        pairs.push((COMMIT, commit));
+    }
+    pairs.push((DATE, date(&environment)));
+    if let Some(target) = environment.get("TARGET") {
+        pairs.push((TARGET, target.clone()));
+    }
+    let rustc = environment.get("RUSTC").map_or("rustc", String::as_str);
+    if let Some(version) = run(Command::new(rustc).arg("--version")) {
+        pairs.push((RUSTC, version));
+    }
+    let mut features: Vec<_> = environment
+        .keys()
+        .filter_map(|key| key.strip_prefix("CARGO_FEATURE_"))
+        .map(|feature| feature.to_lowercase().replace('_', "-"))
+        .collect();
+    features.sort();
+    pairs.push((FEATURES, features.join(", ")));
+    pairs
+}
+
+pub fn watch(directory: &Path) -> Vec<PathBuf> {
+    let git = |arguments: &[&str]| run(Command::new("git").current_dir(directory).args(arguments));
+    let (Some(local), Some(common)) = (
+        git(&["rev-parse", "--git-dir"]),
+        git(&["rev-parse", "--git-common-dir"]),
+    ) else {
+        return Vec::new();
+    };
+    let (local, common) = (directory.join(local), directory.join(common));
+    let mut paths = vec![
+        local.join("HEAD"),
+        local.join("index"),
+        common.join("packed-refs"),
+    ];
+    if let Some(reference) = git(&["symbolic-ref", "-q", "HEAD"]) {
+        paths.push(common.join(reference));
+    }
+    paths.retain(|path| path.exists());
+    paths
+}
+
+pub(crate) const fn equals(left: &str, right: &str) -> bool {
+    let (left, right) = (left.as_bytes(), right.as_bytes());
+    if left.len() != right.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < left.len() {
+        if left[index] != right[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+fn commit(directory: &Path) -> Option<String> {
+    let hash =
+        run(Command::new("git")
+            .current_dir(directory)
+            .args(["rev-parse", "--short=12", "HEAD"]))?;
+    let status = run(Command::new("git").current_dir(directory).args([
+        "status",
+        "--porcelain",
+        "--untracked-files=no",
+    ]));
+    match status {
+        Some(status) if !status.is_empty() => Some(format!("{hash}-dirty")),
+        _ => Some(hash),
+    }
+}
+
+fn date(environment: &HashMap<String, String>) -> String {
+    let seconds = environment
+        .get("SOURCE_DATE_EPOCH")
+        .and_then(|seconds| seconds.parse().ok())
+        .or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .ok()
+                .map(|duration| duration.as_secs())
+        })
+        .unwrap_or_default();
+    let days = (seconds / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day = days.rem_euclid(146_097);
+    let year = (day - day / 1_460 + day / 36_524 - day / 146_096) / 365;
+    let offset = day - (365 * year + year / 4 - year / 100);
+    let month = (5 * offset + 2) / 153;
+    let day = offset - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+fn run(command: &mut Command) -> Option<String> {
+    let output = command.output().ok()?;
+    if output.status.success() {
+        Some(String::from_utf8(output.stdout).ok()?.trim().to_string())
+    } else {
+        None
+    }
+}
@@ -0,0 +1,103 @@
+use std::{
+    env,
+    io::{self, IsTerminal, Write},
+    process::{Command, Stdio},
+};
+use termion::terminal_size;
+
+const PAGER: &str = "less -R";
+
+pub(crate) fn pager(
+    text: &str,
+    enabled: bool,
+    terminal: bool,
+    height: Option<usize>,
+    environment: impl Fn(&str) -> Option<String>,
+) -> Option<Vec<String>> {
+    if !enabled || !terminal {
+        return None;
+    }
+    match height {
+        Some(height) if text.lines().count() >= height => {}
+        _ => return None,
+    }
+
+    let pager = environment("PAGER")
+        .filter(|pager| !pager.trim().is_empty())
+        .unwrap_or_else(|| PAGER.into());
+    let words = pager
+        .split_whitespace()
+        .map(String::from)
+        .collect::<Vec<_>>();
+    (!words.is_empty()).then_some(words)
+}
+
+pub(crate) fn page(text: &str, enabled: bool) -> bool {
+    let height = terminal_size().ok().map(|(_, height)| height as usize);
+    let environment = |key: &str| env::var(key).ok();
+    let Some(command) = pager(
+        text,
+        enabled,
+        io::stdout().is_terminal(),
+        height,
+        environment,
+    ) else {
+        return false;
+    };
+    let Some((program, arguments)) = command.split_first() else {
+        return false;
+    };
+    let Ok(mut child) = Command::new(program)
+        .args(arguments)
+        .stdin(Stdio::piped())
+        .spawn()
+    else {
+        return false;
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(text.as_bytes());
+    }
+    child.wait().is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    #[test]
+    fn pager_pages_tall_text_on_terminals() {
+        let text = "line\n".repeat(30);
+        let unset = |_: &str| None;
+        let most = |key: &str| (key == "PAGER").then(|| "most -s".to_string());
+        let blank = |key: &str| (key == "PAGER").then(|| "  ".to_string());
+        let words = |words: &[&str]| Some(words.iter().map(|word| word.to_string()).collect());
+
+        assert_eq!(
+            pager(&text, true, true, Some(24), unset),
+            words(&["less", "-R"])
+        );
+        assert_eq!(
+            pager(&text, true, true, Some(30), most),
+            words(&["most", "-s"])
+        );
+        assert_eq!(
+            pager(&text, true, true, Some(24), blank),
+            words(&["less", "-R"])
+        );
+        assert_eq!(pager(&text, true, true, Some(31), unset), None);
+        assert_eq!(pager(&text, true, true, None, unset), None);
+        assert_eq!(pager(&text, true, false, Some(24), unset), None);
+        assert_eq!(pager(&text, false, true, Some(24), unset), None);
+    }
+
+    #[test]
+    fn pager_respects_the_builder_switch() -> Result<(), crate::Error> {
+        let text = "line\n".repeat(30);
+        let enabled = Parser::builder().build()?.pager;
+        let disabled = Parser::builder().pager(false).build()?.pager;
+        assert!(pager(&text, enabled, true, Some(24), |_| None).is_some());
+        assert!(pager(&text, disabled, true, Some(24), |_| None).is_none());
+        Ok(())
+    }
+}
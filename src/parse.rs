@@ -1,21 +1,29 @@
 use crate::{
+    describe, doc,
     error::Error,
     help,
-    meta::Meta,
+    locale::{Locale, Localized},
+    man,
+    meta::{Meta, Name},
+    page,
     spell::Spell,
     stack::Stack,
-    style::{self, Format},
-    AUTHOR, BREAK, HELP, LICENSE, MASK, SHIFT, VERSION,
+    style::{self, ColorChoice, Format},
+    AUTHOR, BREAK, BRIEF, COLOR, COLUMNS, COMPLETE, HELP, LICENSE, LIMIT, MASK, SEARCH, SHIFT,
+    VERBOSE, VERSION, WIDTH,
 };
-use core::{cmp::min, marker::PhantomData, num::NonZeroUsize};
+use core::{marker::PhantomData, num::NonZeroUsize};
 use orn::*;
 use regex::RegexSet;
 use std::{
     borrow::Cow,
     collections::{HashMap, HashSet, VecDeque},
-    fmt,
+    fmt, fs,
+    io::{self, IsTerminal, Read},
+    process,
     str::FromStr,
 };
+use termion::{get_tty, input::TermRead, raw::IntoRawMode};
 
 pub struct Context<'a> {
     arguments: &'a mut VecDeque<Cow<'static, str>>,
@@ -27,14 +35,29 @@ pub struct Context<'a> {
     root: Option<&'a Meta>,
     meta: Option<&'a Meta>,
     style: &'a dyn style::Style,
+    color: &'a mut ColorChoice,
+    width: (usize, usize),
+    suggestions: (usize, usize),
+    markup: bool,
+    locale: &'a dyn Locale,
     index: Option<usize>,
+    prefix: Option<&'a str>,
+    terminal: Option<Terminal<'a>>,
 }
 
+pub(crate) struct Terminal<'a>(&'a mut dyn io::BufRead, &'a mut dyn io::Write, bool);
+
 pub struct Parser<P> {
     pub(crate) short: Cow<'static, str>,
     pub(crate) long: Cow<'static, str>,
     pub(crate) parse: P,
     pub(crate) style: Box<dyn style::Style>,
+    pub(crate) color: ColorChoice,
+    pub(crate) width: (usize, usize),
+    pub(crate) suggestions: (usize, usize),
+    pub(crate) pager: bool,
+    pub(crate) markup: bool,
+    pub(crate) locale: Box<dyn Locale>,
 }
 
 #[derive(Default)]
@@ -42,6 +65,7 @@ pub(crate) struct Indices {
     pub indices: HashMap<Cow<'static, str>, usize>,
     pub positions: Vec<usize>,
     pub swizzles: HashSet<char>,
+    pub weights: HashMap<usize, usize>,
 }
 
 pub struct Node<P> {
@@ -52,15 +76,30 @@ pub struct Node<P> {
 pub struct With<P> {
     pub(crate) parse: P,
     pub(crate) set: RegexSet,
-    pub(crate) meta: Meta,
+    pub(crate) meta: Slot,
+}
+
+pub(crate) enum Slot {
+    Owned(Meta),
+    Index(usize),
 }
 
 #[derive(Default)]
 pub struct Value<T> {
     pub(crate) tag: Option<Cow<'static, str>>,
+    pub(crate) source: Source,
+    pub(crate) limit: Option<u64>,
     pub(crate) _marker: PhantomData<T>,
 }
 
+#[derive(Default, Clone, Copy)]
+pub(crate) enum Source {
+    #[default]
+    Value,
+    File,
+    Either,
+}
+
 pub struct Many<P, I, N, F> {
     pub(crate) parse: P,
     pub(crate) per: Option<NonZeroUsize>,
@@ -73,6 +112,8 @@ pub struct Map<P, F>(pub(crate) P, pub(crate) F);
 pub struct Require<P>(pub(crate) P);
 pub struct Default<P, T>(pub(crate) P, pub(crate) T);
 pub struct Environment<P>(pub(crate) P, pub(crate) Cow<'static, str>);
+pub struct Prompt<P>(pub(crate) P, pub(crate) Cow<'static, str>);
+pub struct Complete<P, F>(pub(crate) P, pub(crate) F);
 pub struct At<P = ()>(pub(crate) P);
 
 #[derive(Clone, PartialEq)]
@@ -156,7 +197,7 @@ impl<T: Stack> Stack for At<T> {
 }
 
 impl<'a> Context<'a> {
-    fn own(&mut self) -> Context {
+    fn own(&mut self) -> Context<'_> {
         Context {
             arguments: self.arguments,
             environment: self.environment,
@@ -168,16 +209,46 @@ impl<'a> Context<'a> {
             meta: self.meta,
             index: self.index,
             style: self.style,
+            color: self.color,
+            width: self.width,
+            suggestions: self.suggestions,
+            markup: self.markup,
+            locale: self.locale,
+            prefix: self.prefix,
+            terminal: self
+                .terminal
+                .as_mut()
+                .map(|terminal| Terminal(&mut *terminal.0, &mut *terminal.1, terminal.2)),
         }
     }
 
+    fn style(&self) -> Result<style::Bound<'a>, Error> {
+        let environment = |key: &str| self.environment.get(key).map(|value| value.to_string());
+        let terminal = io::stdout().is_terminal() && io::stderr().is_terminal();
+        let style = if self.color.colored(environment, terminal) {
+            self.style
+        } else {
+            &style::Plain
+        };
+        let resolved = style.resolve(&environment)?;
+        let width = match self.environment.get(COLUMNS).map(|value| value.parse()) {
+            Some(Ok(width)) if width > 0 => width,
+            _ => resolved.as_deref().unwrap_or(style).width(),
+        };
+        let width = width.max(WIDTH).max(self.width.0).min(self.width.1);
+        Ok(style::Bound(style, resolved, width))
+    }
+
     fn key(&mut self, swizzles: &HashSet<char>) -> Result<Option<Cow<'static, str>>, Error> {
         let Some(key) = self.arguments.pop_front() else {
             return Ok(None);
         };
 
         if !self.set.is_empty() && !self.set.is_match(&key) {
-            return Err(self.invalid_argument(key));
+            return match self.prefix {
+                Some(_) => self.key(swizzles),
+                None => Err(self.invalid_argument(key)),
+            };
         }
 
         self.index = None;
@@ -188,7 +259,7 @@ impl<'a> Context<'a> {
                     if swizzles.contains(&key) {
                         self.arguments
                             .push_front(Cow::Owned(format!("{}{key}", self.short)));
-                    } else {
+                    } else if self.prefix.is_none() {
                         return Err(Error::InvalidSwizzleOption(key));
                     }
                 }
@@ -237,9 +308,28 @@ impl<'a> Context<'a> {
         Error::DuplicateOption(self.path.clone())
     }
 
+    fn redact(&self, value: Cow<'static, str>) -> Cow<'static, str> {
+        match self.meta {
+            Some(meta) if Meta::secret(meta.children()) => Cow::Borrowed("***"),
+            _ => value,
+        }
+    }
+
     fn invalid_option(&self, value: Cow<'static, str>) -> Error {
         Error::InvalidOptionValue(
-            value,
+            self.redact(value),
+            self.set
+                .patterns()
+                .iter()
+                .map(|pattern| pattern.trim_matches(['$', '^']).to_string())
+                .collect(),
+            self.path.clone(),
+        )
+    }
+
+    fn invalid_file(&self, file: Cow<'static, str>) -> Error {
+        Error::InvalidOptionFile(
+            file,
             self.set
                 .patterns()
                 .iter()
@@ -250,7 +340,105 @@ impl<'a> Context<'a> {
     }
 
     fn failed_parse(&self, value: Cow<'static, str>) -> Error {
-        Error::FailedToParseOptionValue(value, self.type_name(), self.path.clone())
+        Error::FailedToParseOptionValue(self.redact(value), self.type_name(), self.path.clone())
+    }
+
+    fn prompt<T: FromStr>(&mut self, prompt: &str) -> Option<T> {
+        let patterns: Vec<_> = self
+            .set
+            .patterns()
+            .iter()
+            .map(|pattern| pattern.trim_matches(['$', '^']))
+            .collect();
+        let choices = if patterns
+            .iter()
+            .all(|pattern| regex::escape(pattern) == *pattern)
+        {
+            patterns
+        } else {
+            Vec::new()
+        };
+        let secret = self.meta.is_some_and(|meta| Meta::secret(meta.children()));
+        let mut line = String::new();
+        loop {
+            let Terminal(input, output, tty) = self.terminal.as_mut()?;
+            if choices.is_empty() {
+                write!(output, "{prompt}: ").ok()?;
+            } else {
+                writeln!(output, "{prompt}:").ok()?;
+                for (index, choice) in choices.iter().enumerate() {
+                    writeln!(output, "  {}) {choice}", index + 1).ok()?;
+                }
+                write!(output, "> ").ok()?;
+            }
+            output.flush().ok()?;
+            line.clear();
+            let raw = if secret && *tty {
+                get_tty().and_then(IntoRawMode::into_raw_mode).ok()
+            } else {
+                None
+            };
+            if let Some(raw) = raw {
+                line.push_str(&TermRead::read_line(input).ok()??);
+                drop(raw);
+                writeln!(output).ok()?;
+            } else if io::BufRead::read_line(input, &mut line).ok()? == 0 {
+                return None;
+            }
+            let answer = line.trim();
+            if answer.is_empty() {
+                return None;
+            }
+            let answer = match answer.parse::<usize>() {
+                Ok(index) if index > 0 && index <= choices.len() => choices[index - 1],
+                _ => answer,
+            };
+            let error = match answer.parse::<T>() {
+                Ok(value) if self.set.is_empty() || self.set.is_match(answer) => {
+                    return Some(value)
+                }
+                Ok(_) => self.invalid_option(Cow::Owned(answer.to_string())),
+                Err(_) => self.failed_parse(Cow::Owned(answer.to_string())),
+            };
+            let locale = self.locale;
+            let Terminal(_, output, _) = self.terminal.as_mut()?;
+            writeln!(output, "{}", error.localize(locale)).ok()?;
+        }
+    }
+
+    fn read(&mut self, file: &str, limit: u64) -> Result<Cow<'static, str>, Error> {
+        let mut bytes = Vec::new();
+        let result = match (file, self.terminal.as_mut()) {
+            ("-", Some(Terminal(input, ..))) => input.take(limit + 1).read_to_end(&mut bytes),
+            ("-", None) => io::stdin().lock().take(limit + 1).read_to_end(&mut bytes),
+            (path, _) => fs::File::open(path)
+                .and_then(|handle| handle.take(limit + 1).read_to_end(&mut bytes)),
+        };
+        let result = result.and_then(|size| {
+            if size as u64 > limit {
+                Ok(None)
+            } else {
+                String::from_utf8(bytes)
+                    .map(Some)
+                    .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+            }
+        });
+        match result {
+            Ok(Some(mut content)) => {
+                content.truncate(content.trim_end_matches(['\n', '\r']).len());
+                Ok(Cow::Owned(content))
+            }
+            Ok(None) => Err(Error::OptionFileTooLarge(
+                Cow::Owned(file.to_string()),
+                limit,
+                self.path.clone(),
+            )),
+            Err(error) => Err(Error::FailedToReadOptionFile(
+                Cow::Owned(file.to_string()),
+                Cow::Owned(error.to_string()),
+                self.path.clone(),
+            )),
+        }
     }
 
     fn restore(&mut self, key: Cow<'static, str>) {
@@ -268,7 +456,7 @@ impl<'a> Context<'a> {
         name.cloned()
     }
 
-    fn at(&mut self, index: usize) -> Context {
+    fn at(&mut self, index: usize) -> Context<'_> {
         let mut state = self.own();
         state.index = Some(index);
         state
@@ -289,7 +477,40 @@ impl<'a> Context<'a> {
 
 impl<T, P: Parse<Value = Option<T>>> Parser<P> {
     pub fn parse(&self) -> Result<T, Error> {
-        self.parse_with(std::env::args().skip(1), std::env::vars())
+        let arguments = std::env::args().skip(1);
+        let environment = std::env::vars();
+        if io::stdin().is_terminal() {
+            let mut input = io::stdin().lock();
+            let terminal = Terminal(&mut input, &mut io::stderr(), true);
+            self.parse_in(arguments, environment, Some(terminal))
+        } else {
+            self.parse_with(arguments, environment)
+        }
+    }
+
+    pub fn parse_or_exit(&self) -> T {
+        match self.parse() {
+            Ok(value) => value,
+            Err(Error::Help(Some(help))) => {
+                if !page::page(&help, self.pager) {
+                    println!("{help}");
+                }
+                process::exit(0)
+            }
+            Err(
+                error @ (Error::Version(Some(_))
+                | Error::License(Some(_))
+                | Error::Author(Some(_))
+                | Error::Complete(_)),
+            ) => {
+                println!("{error}");
+                process::exit(0)
+            }
+            Err(error) => {
+                eprintln!("{}", error.localize(&*self.locale));
+                process::exit(2)
+            }
+        }
     }
 
     pub fn parse_with<
@@ -301,16 +522,56 @@ impl<T, P: Parse<Value = Option<T>>> Parser<P> {
         arguments: impl IntoIterator<Item = A>,
         environment: impl IntoIterator<Item = (K, V)>,
     ) -> Result<T, Error> {
+        self.parse_in(arguments, environment, None)
+    }
+
+    pub fn prompt_with<
+        A: Into<Cow<'static, str>>,
+        K: Into<Cow<'static, str>>,
+        V: Into<Cow<'static, str>>,
+    >(
+        &self,
+        arguments: impl IntoIterator<Item = A>,
+        environment: impl IntoIterator<Item = (K, V)>,
+        mut input: impl io::BufRead,
+        mut output: impl io::Write,
+    ) -> Result<T, Error> {
+        self.parse_in(
+            arguments,
+            environment,
+            Some(Terminal(&mut input, &mut output, false)),
+        )
+    }
+
+    fn parse_in<
+        A: Into<Cow<'static, str>>,
+        K: Into<Cow<'static, str>>,
+        V: Into<Cow<'static, str>>,
+    >(
+        &self,
+        arguments: impl IntoIterator<Item = A>,
+        environment: impl IntoIterator<Item = (K, V)>,
+        mut terminal: Option<Terminal>,
+    ) -> Result<T, Error> {
+        let mut environment: HashMap<_, _> = environment
+            .into_iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .filter(|(key, _)| !key.chars().all(char::is_whitespace))
+            .collect();
+        if let Some(Ok(cursor)) = environment.remove(COMPLETE).map(|cursor| cursor.parse()) {
+            return Err(Error::Complete(self.complete(
+                arguments.into_iter().map(Into::into).collect(),
+                environment,
+                cursor,
+            )));
+        }
+
         let mut arguments = arguments
             .into_iter()
             .map(Into::into)
             .filter(|argument| !argument.chars().all(char::is_whitespace))
             .collect();
-        let mut environment = environment
-            .into_iter()
-            .map(|(key, value)| (key.into(), value.into()))
-            .filter(|(key, _)| !key.chars().all(char::is_whitespace))
-            .collect();
+        let mut color = self.color;
         let mut context = Context {
             arguments: &mut arguments,
             environment: &mut environment,
@@ -322,6 +583,15 @@ impl<T, P: Parse<Value = Option<T>>> Parser<P> {
             root: None,
             meta: None,
             style: &*self.style,
+            color: &mut color,
+            width: self.width,
+            suggestions: self.suggestions,
+            markup: self.markup,
+            locale: &*self.locale,
+            prefix: None,
+            terminal: terminal
+                .as_mut()
+                .map(|terminal| Terminal(&mut *terminal.0, &mut *terminal.1, terminal.2)),
         };
         let state = self.parse.initialize(context.own())?;
         let state = self.parse.parse(state, context.own())?;
@@ -335,6 +605,134 @@ impl<T, P: Parse<Value = Option<T>>> Parser<P> {
             Err(Error::ExcessArguments(arguments))
         }
     }
+
+    pub fn complete_with<
+        A: Into<Cow<'static, str>>,
+        K: Into<Cow<'static, str>>,
+        V: Into<Cow<'static, str>>,
+    >(
+        &self,
+        arguments: impl IntoIterator<Item = A>,
+        environment: impl IntoIterator<Item = (K, V)>,
+        cursor: usize,
+    ) -> Vec<Cow<'static, str>> {
+        self.complete(
+            arguments.into_iter().map(Into::into).collect(),
+            environment
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .filter(|(key, _)| !key.chars().all(char::is_whitespace))
+                .collect(),
+            cursor,
+        )
+    }
+
+    fn complete(
+        &self,
+        mut arguments: VecDeque<Cow<'static, str>>,
+        mut environment: HashMap<Cow<'static, str>, Cow<'static, str>>,
+        cursor: usize,
+    ) -> Vec<Cow<'static, str>> {
+        let Some(cursor) = cursor.checked_sub(1) else {
+            return Vec::new();
+        };
+        arguments.truncate(cursor.saturating_add(1));
+        let prefix = if arguments.len() > cursor {
+            arguments.pop_back().unwrap_or_default()
+        } else {
+            Cow::Borrowed("")
+        };
+        arguments.retain(|argument| !argument.chars().all(char::is_whitespace));
+        let mut color = self.color;
+        let mut context = Context {
+            arguments: &mut arguments,
+            environment: &mut environment,
+            path: &mut Vec::new(),
+            short: &self.short,
+            long: &self.long,
+            set: &RegexSet::empty(),
+            index: None,
+            root: None,
+            meta: None,
+            style: &*self.style,
+            color: &mut color,
+            width: self.width,
+            suggestions: self.suggestions,
+            markup: self.markup,
+            locale: &*self.locale,
+            prefix: Some(&prefix),
+            terminal: None,
+        };
+        let result = self
+            .parse
+            .initialize(context.own())
+            .and_then(|state| self.parse.parse(state, context.own()));
+        match result {
+            Err(Error::Complete(candidates)) => candidates,
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl<T, P: Parse<Value = Option<T>>> Parser<With<P>> {
+    pub fn check_examples(&self) -> Result<(), Error> {
+        fn examples<'a>(metas: &'a [Meta], found: &mut Vec<&'a Cow<'static, str>>) {
+            for meta in metas {
+                match meta {
+                    Meta::Example(command, _) => found.push(command),
+                    Meta::Verb(metas) | Meta::Group(metas) => examples(metas, found),
+                    _ => {}
+                }
+            }
+        }
+
+        let mut found = Vec::new();
+        examples(self.root().children(), &mut found);
+        for command in found {
+            let arguments = words(command).into_iter().skip(1);
+            match self.parse_with(arguments, [("", "")]) {
+                Ok(_)
+                | Err(
+                    Error::Help(_)
+                    | Error::Brief
+                    | Error::Version(_)
+                    | Error::Verbose
+                    | Error::License(_)
+                    | Error::Author(_)
+                    | Error::Complete(_),
+                ) => {}
+                Err(error) => return Err(Error::InvalidExample(command.clone(), Box::new(error))),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<P> Parser<With<P>> {
+    pub fn man(&self) -> Result<Vec<(String, String)>, Error> {
+        Ok(man::pages(self.root(), &*self.locale, self.markup)?)
+    }
+
+    pub fn markdown(&self) -> Result<String, Error> {
+        Ok(doc::markdown(self.root(), &*self.locale, self.markup)?)
+    }
+
+    pub fn html(&self) -> Result<String, Error> {
+        Ok(doc::html(self.root(), &*self.locale, self.markup)?)
+    }
+
+    pub fn localize<'a>(&'a self, error: &'a Error) -> Localized<'a> {
+        error.localize(&*self.locale)
+    }
+
+    pub fn describe(&self) -> describe::Command {
+        describe::describe(self.root())
+    }
+
+    fn root(&self) -> &Meta {
+        static EMPTY: Meta = Meta::Group(Vec::new());
+        self.parse.meta(None).unwrap_or(&EMPTY)
+    }
 }
 
 impl<P: Parse + ?Sized> Parse for Box<P> {
@@ -417,23 +815,92 @@ impl<P: Parse> Parse for Node<P> {
 
         let mut positions = self.indices.positions.iter().copied().enumerate();
         while let Some(key) = context.key(&self.indices.swizzles)? {
+            let key = match self.split(&context, key) {
+                (key, Some(value)) => {
+                    context.restore(value);
+                    key
+                }
+                (key, None) => key,
+            };
             let (key, index) = match self.indices.indices.get(&key).copied() {
-                Some(HELP) => return Err(Error::Help(None)),
+                Some(HELP | BRIEF | VERSION | VERBOSE | LICENSE | AUTHOR | SEARCH)
+                    if context.prefix.is_some() =>
+                {
+                    continue
+                }
+                Some(COLOR) => {
+                    let mut path = context.path.clone();
+                    path.push(Key::Name(key));
+                    match context.arguments.pop_front() {
+                        Some(value) => match value.parse() {
+                            Ok(color) => *context.color = color,
+                            Err(_) if context.prefix.is_some() => {}
+                            Err(_) => {
+                                return Err(Error::FailedToParseOptionValue(
+                                    value,
+                                    Some(Cow::Borrowed("color")),
+                                    path,
+                                ))
+                            }
+                        },
+                        None if context.prefix.is_some() => {}
+                        None => {
+                            return Err(Error::MissingOptionValue(
+                                Some(Cow::Borrowed("color")),
+                                path,
+                            ))
+                        }
+                    }
+                    continue;
+                }
+                Some(HELP) => {
+                    self.color(&mut context);
+                    return Err(Error::Help(None));
+                }
+                Some(BRIEF) => {
+                    self.color(&mut context);
+                    return Err(Error::Brief);
+                }
+                Some(SEARCH) => {
+                    self.color(&mut context);
+                    return Err(match context.arguments.pop_front() {
+                        Some(keyword) => Error::Search(keyword),
+                        None => {
+                            let mut path = context.path.clone();
+                            path.push(Key::Name(key));
+                            Error::MissingOptionValue(Some(Cow::Borrowed("string")), path)
+                        }
+                    });
+                }
+                Some(VERSION) if self.take(&mut context, VERBOSE) => return Err(Error::Verbose),
                 Some(VERSION) => return Err(Error::Version(None)),
+                Some(VERBOSE) if self.take(&mut context, VERSION) => return Err(Error::Verbose),
+                Some(VERBOSE) => return Err(Error::UnrecognizedArgument(key, Vec::new())),
                 Some(LICENSE) => return Err(Error::License(None)),
                 Some(AUTHOR) => return Err(Error::Author(None)),
-                Some(BREAK) => break,
+                Some(BREAK) => return Ok(Some(self.parse.finalize(outer, context.own())?)),
                 Some(index) => (Key::Name(key), index),
                 None => match positions.next() {
                     Some((i, index)) => {
                         context.restore(key);
                         (Key::Index(i), index)
                     }
+                    None if context.prefix.is_some() => continue,
                     None => {
+                        let owner = context
+                            .root
+                            .and_then(|root| help::owner(root, &key, context.path));
+                        if let Some(path) = owner {
+                            return Err(Error::MisplacedArgument(key, path));
+                        }
+                        let dictionary = self.indices.indices.iter().map(|(key, index)| {
+                            let weight = self.indices.weights.get(index).copied();
+                            (key.clone(), weight.unwrap_or_default())
+                        });
                         let suggestions = Spell::new().suggest(
                             &key,
-                            self.indices.indices.keys().cloned(),
-                            min(key.len() / 3, 3),
+                            dictionary,
+                            Spell::maximum(&key, context.suggestions),
                         );
                         return Err(Error::UnrecognizedArgument(key, suggestions));
                     }
@@ -443,7 +910,10 @@ impl<P: Parse> Parse for Node<P> {
             outer = self.parse.parse(outer, context.at(index))?;
             context.path.pop();
         }
-        Ok(Some(self.parse.finalize(outer, context.own())?))
+        match context.prefix {
+            Some(prefix) => Err(self.complete(outer, positions.next(), prefix, context)),
+            None => Ok(Some(self.parse.finalize(outer, context.own())?)),
+        }
     }
 
     fn finalize(&self, state: Self::State, _: Context) -> Result<Self::Value, Error> {
@@ -451,75 +921,226 @@ impl<P: Parse> Parse for Node<P> {
     }
 }
 
+impl<P: Parse> Node<P> {
+    fn take(&self, context: &mut Context, index: usize) -> bool {
+        let position = context
+            .arguments
+            .iter()
+            .position(|argument| self.indices.indices.get(argument) == Some(&index));
+        position
+            .and_then(|index| context.arguments.remove(index))
+            .is_some()
+    }
+
+    fn split(
+        &self,
+        context: &Context,
+        key: Cow<'static, str>,
+    ) -> (Cow<'static, str>, Option<Cow<'static, str>>) {
+        if !key.starts_with(context.long) || self.indices.indices.contains_key(&key) {
+            return (key, None);
+        }
+        match key.split_once('=') {
+            Some((name, value)) if self.indices.indices.contains_key(name) => (
+                Cow::Owned(name.to_string()),
+                Some(Cow::Owned(value.to_string())),
+            ),
+            _ => (key, None),
+        }
+    }
+
+    fn color(&self, context: &mut Context) {
+        let mut index = 0;
+        while index < context.arguments.len() {
+            let (key, value) = self.split(context, context.arguments[index].clone());
+            if self.indices.indices.get(&key) == Some(&COLOR) {
+                context.arguments.remove(index);
+                let value = value.or_else(|| context.arguments.remove(index));
+                if let Some(Ok(color)) = value.map(|value| value.parse()) {
+                    *context.color = color;
+                }
+            } else {
+                index += 1;
+            }
+        }
+    }
+
+    fn complete(
+        &self,
+        state: P::State,
+        position: Option<(usize, usize)>,
+        prefix: &str,
+        mut context: Context,
+    ) -> Error {
+        fn names(metas: &[Meta], prefix: &str, candidates: &mut Vec<Cow<'static, str>>) {
+            for meta in Meta::visible(metas) {
+                match meta {
+                    Meta::Group(metas) => names(metas, prefix, candidates),
+                    Meta::Option(metas) | Meta::Verb(metas) => {
+                        for meta in Meta::visible(metas) {
+                            match meta {
+                                Meta::Name(_, name) if name.starts_with(prefix) => {
+                                    candidates.push(name.clone())
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut candidates = Vec::new();
+        if let Some((i, index)) = position {
+            context.path.push(Key::Index(i));
+            if let Err(Error::Complete(values)) = self.parse.parse(state, context.at(index)) {
+                candidates.extend(values);
+            }
+            context.path.pop();
+        }
+        if let Some(meta) = context.meta {
+            names(meta.children(), prefix, &mut candidates);
+        }
+        Error::Complete(candidates)
+    }
+}
+
+impl<P> With<P> {
+    fn meta<'a>(&'a self, parent: Option<&'a Meta>) -> Option<&'a Meta> {
+        match &self.meta {
+            Slot::Owned(meta) => Some(meta),
+            Slot::Index(index) => parent?.children().get(*index),
+        }
+    }
+}
+
 impl<P: Parse> Parse for With<P> {
     type State = P::State;
     type Value = P::Value;
 
     fn initialize(&self, mut context: Context) -> Result<Self::State, Error> {
+        let meta = self.meta(context.meta).ok_or(Error::InvalidParseState)?;
         match self
             .parse
-            .initialize(context.with(Some(&self.meta), Some(&self.set)))
+            .initialize(context.with(Some(meta), Some(&self.set)))
         {
             Ok(state) => Ok(state),
-            Err(error) => Err(fill(
-                error,
-                context.root.unwrap_or(&self.meta),
-                &self.meta,
-                context.path,
-                context.style,
-            )),
+            Err(error) => Err(fill(error, meta, context)),
         }
     }
 
     fn parse(&self, state: Self::State, mut context: Context) -> Result<Self::State, Error> {
+        let meta = self.meta(context.meta).ok_or(Error::InvalidParseState)?;
         match self
             .parse
-            .parse(state, context.with(Some(&self.meta), Some(&self.set)))
+            .parse(state, context.with(Some(meta), Some(&self.set)))
         {
             Ok(state) => Ok(state),
-            Err(error) => Err(fill(
-                error,
-                context.root.unwrap_or(&self.meta),
-                &self.meta,
-                context.path,
-                context.style,
-            )),
+            Err(error) => Err(fill(error, meta, context)),
         }
     }
 
     fn finalize(&self, state: Self::State, mut context: Context) -> Result<Self::Value, Error> {
+        let meta = self.meta(context.meta).ok_or(Error::InvalidParseState)?;
         match self
             .parse
-            .finalize(state, context.with(Some(&self.meta), Some(&self.set)))
+            .finalize(state, context.with(Some(meta), Some(&self.set)))
         {
             Ok(value) => Ok(value),
-            Err(error) => Err(fill(
-                error,
-                context.root.unwrap_or(&self.meta),
-                &self.meta,
-                context.path,
-                context.style,
-            )),
+            Err(error) => Err(fill(error, meta, context)),
+        }
+    }
+}
+
+fn words(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = None::<String>;
+    let mut quote = None;
+    let mut characters = text.chars();
+    while let Some(character) = characters.next() {
+        match (quote, character) {
+            (None, '\'' | '"') => {
+                quote = Some(character);
+                word.get_or_insert_with(String::new);
+            }
+            (Some(open), character) if open == character => quote = None,
+            (None | Some('"'), '\\') => {
+                let word = word.get_or_insert_with(String::new);
+                word.extend(characters.next());
+            }
+            (None, character) if character.is_whitespace() => words.extend(word.take()),
+            (_, character) => word.get_or_insert_with(String::new).push(character),
         }
     }
+    words.extend(word);
+    words
 }
 
-fn fill<S: style::Style + ?Sized>(
-    error: Error,
-    root: &Meta,
-    meta: &Meta,
-    path: &[Key],
-    style: &S,
-) -> Error {
+fn fill(error: Error, meta: &Meta, context: Context) -> Error {
+    let root = context.root.unwrap_or(meta);
     match error {
-        Error::Help(None) => Error::Help(help::help(root, meta, path, style)),
+        Error::Help(None) | Error::Brief | Error::Search(_) => {
+            let style = match context.style() {
+                Ok(style) => style,
+                Err(error) => return error,
+            };
+            match error {
+                Error::Search(keyword) => Error::Help(help::search(
+                    root,
+                    &keyword,
+                    &style,
+                    context.locale,
+                    context.suggestions,
+                    context.markup,
+                )),
+                error => topic(meta, context, &style, matches!(error, Error::Brief)),
+            }
+        }
         Error::Version(None) => Error::Version(help::version(meta, 1)),
+        Error::Verbose => Error::Version(help::verbose(meta, 1)),
         Error::License(None) => Error::License(help::license(meta, 1)),
         Error::Author(None) => Error::Author(help::author(meta, 1)),
         _ => error,
     }
 }
 
+fn topic(mut meta: &Meta, context: Context, style: &style::Bound, brief: bool) -> Error {
+    let root = context.root.unwrap_or(meta);
+    let (arguments, locale, markup) = (context.arguments, context.locale, context.markup);
+    let mut path = context.path.clone();
+    let origin = meta;
+    while let Some(argument) = arguments.pop_front() {
+        match help::find(meta.children(), &argument) {
+            Some(verb @ Meta::Verb(_)) => {
+                meta = verb;
+                path.push(Key::Name(argument));
+            }
+            Some(Meta::Option(metas)) if arguments.is_empty() => {
+                let option = Meta::Group(vec![
+                    Meta::Name(Name::Plain, argument),
+                    Meta::Option(metas.clone()),
+                ]);
+                return Error::Help(help::help(
+                    root, &option, &path, style, locale, brief, markup,
+                ));
+            }
+            Some(group @ Meta::Group(_)) if arguments.is_empty() => {
+                return Error::Help(help::help(root, group, &path, style, locale, brief, markup));
+            }
+            Some(Meta::Topic(name, text)) if arguments.is_empty() => {
+                return Error::Help(help::topic(name, text, style, locale, markup));
+            }
+            _ => {
+                meta = origin;
+                path.truncate(context.path.len());
+                break;
+            }
+        }
+    }
+    Error::Help(help::help(root, meta, &path, style, locale, brief, markup))
+}
+
 impl<P: Parse, T, F: Fn(P::Value) -> Result<T, Error>> Parse for Map<P, F> {
     type State = P::State;
     type Value = T;
@@ -533,7 +1154,7 @@ impl<P: Parse, T, F: Fn(P::Value) -> Result<T, Error>> Parse for Map<P, F> {
     }
 
     fn finalize(&self, state: Self::State, context: Context) -> Result<Self::Value, Error> {
-        self.1(self.0.finalize(state, context)?).map_err(Into::into)
+        self.1(self.0.finalize(state, context)?)
     }
 }
 
@@ -597,7 +1218,7 @@ impl<T: FromStr, P: Parse<Value = Option<T>>> Parse for Environment<P> {
                     Ok(value) => Ok(Some(value)),
                     Err(_) => Err(Error::FailedToParseEnvironmentVariable(
                         self.1.clone(),
-                        value.clone(),
+                        context.redact(value.clone()),
                         context.type_name(),
                         context.path.clone(),
                         context.meta.and_then(Meta::key),
@@ -609,6 +1230,50 @@ impl<T: FromStr, P: Parse<Value = Option<T>>> Parse for Environment<P> {
     }
 }
 
+impl<T: FromStr, P: Parse<Value = Option<T>>> Parse for Prompt<P> {
+    type State = P::State;
+    type Value = P::Value;
+
+    fn initialize(&self, context: Context) -> Result<Self::State, Error> {
+        self.0.initialize(context)
+    }
+
+    fn parse(&self, state: Self::State, context: Context) -> Result<Self::State, Error> {
+        self.0.parse(state, context)
+    }
+
+    fn finalize(&self, state: Self::State, mut context: Context) -> Result<Self::Value, Error> {
+        match self.0.finalize(state, context.own())? {
+            Some(value) => Ok(Some(value)),
+            None if context.prefix.is_some() => Ok(None),
+            None => Ok(context.prompt(&self.1)),
+        }
+    }
+}
+
+impl<P: Parse, F: Fn(&str) -> Vec<String>> Parse for Complete<P, F> {
+    type State = P::State;
+    type Value = P::Value;
+
+    fn initialize(&self, context: Context) -> Result<Self::State, Error> {
+        self.0.initialize(context)
+    }
+
+    fn parse(&self, state: Self::State, context: Context) -> Result<Self::State, Error> {
+        let prefix = context.prefix;
+        match (self.0.parse(state, context), prefix) {
+            (Err(Error::Complete(_)), Some(prefix)) => Err(Error::Complete(
+                self.1(prefix).into_iter().map(Cow::Owned).collect(),
+            )),
+            (result, _) => result,
+        }
+    }
+
+    fn finalize(&self, state: Self::State, context: Context) -> Result<Self::Value, Error> {
+        self.0.finalize(state, context)
+    }
+}
+
 impl<T: FromStr> Parse for Value<T> {
     type State = Option<T>;
     type Value = Option<T>;
@@ -618,7 +1283,7 @@ impl<T: FromStr> Parse for Value<T> {
     }
 
     fn parse(&self, state: Self::State, mut context: Context) -> Result<Self::State, Error> {
-        if state.is_some() {
+        if state.is_some() && context.prefix.is_none() {
             return Err(context.duplicate_option());
         }
         let argument = match (context.arguments.pop_front(), &self.tag, &mut context.index) {
@@ -630,16 +1295,40 @@ impl<T: FromStr> Parse for Value<T> {
                 }
                 Err(_) => return Err(context.failed_parse(tag.clone())),
             },
+            _ if context.prefix.is_some() => return Err(Error::Complete(Vec::new())),
             _ => return Err(context.missing_option()),
         };
+        let limit = self.limit.unwrap_or(LIMIT);
+        let (argument, file) = match (self.source, context.prefix) {
+            (_, Some(_)) | (Source::Value, None) => (argument, None),
+            (Source::File, None) => (context.read(&argument, limit)?, Some(argument)),
+            (Source::Either, None) => match argument.strip_prefix('@') {
+                Some(file) => (
+                    context.read(file, limit)?,
+                    Some(Cow::Owned(file.to_string())),
+                ),
+                None => (argument, None),
+            },
+        };
         match (argument.parse::<T>(), &self.tag, &mut context.index) {
             (Ok(value), _, _) => {
                 if context.set.is_empty() || context.set.is_match(&argument) {
                     Ok(Some(value))
+                } else if context.prefix.is_some() {
+                    Ok(state)
+                } else if let Some(file) = file {
+                    Err(context.invalid_file(file))
                 } else {
                     Err(context.invalid_option(argument))
                 }
             }
+            (Err(_), _, _) if file.is_some() && context.prefix.is_none() => {
+                Err(Error::FailedToParseOptionFile(
+                    file.unwrap_or_default(),
+                    context.type_name(),
+                    context.path.clone(),
+                ))
+            }
             (Err(_), Some(tag), Some(index)) if *index == 0 => {
                 context.arguments.push_front(argument);
                 *index += 1;
@@ -648,6 +1337,7 @@ impl<T: FromStr> Parse for Value<T> {
                         .map_err(|_| context.failed_parse(tag.clone()))?,
                 ))
             }
+            (Err(_), _, _) if context.prefix.is_some() => Ok(state),
             (Err(_), _, _) => Err(context.failed_parse(argument)),
         }
     }
@@ -1,52 +1,101 @@
-use core::{mem::swap, ops::Deref};
+use core::{cmp::Reverse, mem::swap, ops::Deref};
 
-pub struct Spell(Vec<usize>, Vec<usize>);
+pub struct Spell {
+    rows: [Vec<usize>; 3],
+    left: Vec<char>,
+    right: Vec<char>,
+}
 
 impl Spell {
     pub const fn new() -> Self {
-        Self(Vec::new(), Vec::new())
+        Self {
+            rows: [Vec::new(), Vec::new(), Vec::new()],
+            left: Vec::new(),
+            right: Vec::new(),
+        }
+    }
+
+    pub fn maximum(word: &str, (ratio, maximum): (usize, usize)) -> usize {
+        word.chars()
+            .count()
+            .checked_div(ratio)
+            .map_or(maximum, |count| count.min(maximum))
     }
 
     pub fn suggest<T: Deref<Target = str>>(
         &mut self,
         word: &str,
-        dictionary: impl IntoIterator<Item = T>,
+        dictionary: impl IntoIterator<Item = (T, usize)>,
         maximum: usize,
     ) -> Vec<(T, usize)> {
         let dictionary = dictionary.into_iter();
-        let mut results = Vec::with_capacity(dictionary.size_hint().0);
-        for candidate in dictionary {
-            let distance = self.distance(word.as_bytes(), candidate.as_bytes());
+        let mut results: Vec<(T, usize, usize, usize)> =
+            Vec::with_capacity(dictionary.size_hint().0);
+        for (candidate, weight) in dictionary {
+            let distance = self.distance(word, &candidate);
             if distance <= maximum {
-                results.push((candidate, distance));
+                let prefix = Self::prefix(word, &candidate);
+                results.push((candidate, distance, prefix, weight));
             }
         }
-        results.sort_by_key(|&(_, distance)| distance);
+        results.sort_by(|left, right| {
+            (left.1, Reverse(left.2), Reverse(left.3), &*left.0).cmp(&(
+                right.1,
+                Reverse(right.2),
+                Reverse(right.3),
+                &*right.0,
+            ))
+        });
         results
+            .into_iter()
+            .map(|(candidate, distance, ..)| (candidate, distance))
+            .collect()
     }
 
-    fn distance(&mut self, left: &[u8], right: &[u8]) -> usize {
-        let left_count = left.len();
-        let right_count = right.len();
-        if left_count > right_count {
-            return self.distance(right, left);
+    fn prefix(left: &str, right: &str) -> usize {
+        left.chars()
+            .flat_map(char::to_lowercase)
+            .zip(right.chars().flat_map(char::to_lowercase))
+            .take_while(|(left, right)| left == right)
+            .count()
+    }
+
+    fn distance(&mut self, left: &str, right: &str) -> usize {
+        let Self {
+            rows: [before, previous, current],
+            left: lefts,
+            right: rights,
+        } = self;
+        lefts.clear();
+        lefts.extend(left.chars().flat_map(char::to_lowercase));
+        rights.clear();
+        rights.extend(right.chars().flat_map(char::to_lowercase));
+        if lefts.len() > rights.len() {
+            swap(lefts, rights);
         }
+        let left_count = lefts.len();
+        let right_count = rights.len();
 
-        let Self(previous, current) = self;
+        before.clear();
+        before.resize(left_count + 1, 0);
         previous.clear();
-        previous.resize(left_count + 1, 0);
+        previous.extend(0..=left_count);
         current.resize(left_count + 1, 0);
 
         for i in 1..=right_count {
             current[0] = i;
             for j in 1..=left_count {
-                let left = char::from(left[j - 1]).to_ascii_lowercase();
-                let right = char::from(right[i - 1]).to_ascii_lowercase();
+                let left = lefts[j - 1];
+                let right = rights[i - 1];
                 let insert = current[j - 1] + 1;
                 let delete = previous[j] + 1;
                 let replace = previous[j - 1] + if left == right { 0 } else { 1 };
                 current[j] = insert.min(delete).min(replace);
+                if i > 1 && j > 1 && left == rights[i - 2] && lefts[j - 2] == right {
+                    current[j] = current[j].min(before[j - 2] + 1);
+                }
             }
+            swap(before, previous);
             swap(previous, current);
         }
 
@@ -61,11 +110,25 @@ mod tests {
     #[test]
     fn distance() {
         let mut spell = Spell::new();
-        assert_eq!(spell.distance(b"boba", b"boba"), 0);
-        assert_eq!(spell.distance(b"boba", b"bobo"), 1);
-        assert_eq!(spell.distance(b"boba", b"bobba"), 1);
-        assert_eq!(spell.distance(b"boba", b"boa"), 1);
-        assert_eq!(spell.distance(b"boba", b"fett"), 4);
+        assert_eq!(spell.distance("boba", "boba"), 0);
+        assert_eq!(spell.distance("boba", "bobo"), 1);
+        assert_eq!(spell.distance("boba", "bobba"), 1);
+        assert_eq!(spell.distance("boba", "boa"), 1);
+        assert_eq!(spell.distance("boba", "fett"), 4);
+        assert_eq!(spell.distance("--verbose", "--verobse"), 1);
+        assert_eq!(spell.distance("ab", "ba"), 1);
+        assert_eq!(spell.distance("héllo", "HÉLLO"), 0);
+        assert_eq!(spell.distance("naïve", "naive"), 1);
+        assert_eq!(spell.distance("日本語", "日本"), 1);
+    }
+
+    #[test]
+    fn maximum() {
+        assert_eq!(Spell::maximum("--verbose", (3, 3)), 3);
+        assert_eq!(Spell::maximum("--ab", (3, 3)), 1);
+        assert_eq!(Spell::maximum("日本語", (3, 3)), 1);
+        assert_eq!(Spell::maximum("--verbose", (0, 2)), 2);
+        assert_eq!(Spell::maximum("--verbose", (3, 0)), 0);
     }
 
     #[test]
@@ -83,19 +146,26 @@ mod tests {
                 "poulaye",
                 "p",
                 "poulay",
-            ],
+            ]
+            .map(|word| (word, 0)),
             2,
         );
         assert_eq!(
             results,
             vec![
                 ("poulaye", 0),
-                ("piulaye", 1),
                 ("poulay", 1),
+                ("piulaye", 1),
+                ("poulah", 2),
                 ("poullayye", 2),
-                ("pilaye", 2),
-                ("poulah", 2)
+                ("pilaye", 2)
             ]
         );
     }
+
+    #[test]
+    fn suggest_weighted() {
+        let results = Spell::new().suggest("abc", [("abd", 0), ("abe", 2), ("xbc", 0)], 1);
+        assert_eq!(results, vec![("abe", 1), ("abd", 1), ("xbc", 1)]);
+    }
 }
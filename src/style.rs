@@ -1,16 +1,68 @@
 use self::color::*;
+use crate::{error::Error, THEME};
 use std::{
     borrow::Cow,
     fmt::{self, Display},
+    str::FromStr,
+    sync::OnceLock,
 };
 use termion::{
-    color::{Bg, Color, Fg, Rgb},
+    color::{AnsiValue, Bg, Color, Fg, Rgb},
     style::{Bold, Faint, Italic, Reset, Underline},
     terminal_size,
 };
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 pub struct Termion;
+pub struct Themed {
+    theme: Theme,
+    depth: Option<Depth>,
+    links: Option<Links>,
+    begins: [(Paint, &'static str); ITEMS],
+}
 pub struct Plain;
+pub(crate) struct Bound<'a>(pub &'a dyn Style, pub Option<Box<dyn Style>>, pub usize);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Spec {
+    pub foreground: Option<Rgb>,
+    pub background: Option<Rgb>,
+    pub bold: bool,
+    pub italic: bool,
+    pub faint: bool,
+    pub underline: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Depth {
+    True,
+    Ansi256,
+    Ansi16,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Links {
+    Enabled,
+    Disabled,
+}
+
+pub(crate) struct Hyperlink<'a, T>(pub Option<&'a str>, pub T);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorChoice {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+#[derive(Clone, Copy)]
+struct Paint(Spec, Depth);
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Theme {
+    specs: [Spec; ITEMS],
+}
 
 #[derive(Clone, Copy)]
 #[non_exhaustive]
@@ -31,6 +83,12 @@ pub enum Item {
     Link,
     Summary,
     Tag,
+    Example,
+    Strong,
+    Emphasis,
+    Code,
+    Bullet,
+    Block,
 }
 
 #[derive(Clone, Copy)]
@@ -41,6 +99,448 @@ pub enum Line {
     Usage,
 }
 
+const ITEMS: usize = 28;
+const NAMES: [&str; ITEMS] = [
+    "head",
+    "bar.head",
+    "bar.description",
+    "bar.link",
+    "bar.usage",
+    "arrow.head",
+    "arrow.description",
+    "arrow.link",
+    "arrow.usage",
+    "version",
+    "description",
+    "author",
+    "help",
+    "group",
+    "verb",
+    "type",
+    "option",
+    "usage",
+    "note",
+    "link",
+    "summary",
+    "tag",
+    "example",
+    "strong",
+    "emphasis",
+    "code",
+    "bullet",
+    "block",
+];
+
+impl Item {
+    const fn index(self) -> usize {
+        match self {
+            Item::Head => 0,
+            Item::Bar(Line::Head) => 1,
+            Item::Bar(Line::Description) => 2,
+            Item::Bar(Line::Link) => 3,
+            Item::Bar(Line::Usage) => 4,
+            Item::Arrow(Line::Head) => 5,
+            Item::Arrow(Line::Description) => 6,
+            Item::Arrow(Line::Link) => 7,
+            Item::Arrow(Line::Usage) => 8,
+            Item::Version => 9,
+            Item::Description => 10,
+            Item::Author => 11,
+            Item::Help => 12,
+            Item::Group => 13,
+            Item::Verb => 14,
+            Item::Type => 15,
+            Item::Option => 16,
+            Item::Usage => 17,
+            Item::Note => 18,
+            Item::Link => 19,
+            Item::Summary => 20,
+            Item::Tag => 21,
+            Item::Example => 22,
+            Item::Strong => 23,
+            Item::Emphasis => 24,
+            Item::Code => 25,
+            Item::Bullet => 26,
+            Item::Block => 27,
+        }
+    }
+
+    const fn prefix(self) -> &'static str {
+        match self {
+            Item::Bar(_) => "│",
+            Item::Arrow(_) => ">",
+            Item::Type => "<",
+            Item::Tag => "[",
+            Item::Bullet => "•",
+            _ => "",
+        }
+    }
+}
+
+impl Spec {
+    pub const fn new() -> Self {
+        Self {
+            foreground: None,
+            background: None,
+            bold: false,
+            italic: false,
+            faint: false,
+            underline: false,
+        }
+    }
+
+    pub const fn foreground(mut self, color: Rgb) -> Self {
+        self.foreground = Some(color);
+        self
+    }
+
+    pub const fn background(mut self, color: Rgb) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    pub const fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    pub const fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    pub const fn faint(mut self) -> Self {
+        self.faint = true;
+        self
+    }
+
+    pub const fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+}
+
+impl FromStr for Spec {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut spec = Spec::new();
+        let mut words = text.split_whitespace();
+        while let Some(word) = words.next() {
+            spec = match word {
+                "bold" => spec.bold(),
+                "italic" => spec.italic(),
+                "faint" => spec.faint(),
+                "underline" => spec.underline(),
+                "on" => match words.next().and_then(rgb) {
+                    Some(color) => spec.background(color),
+                    None => return Err(Error::InvalidThemeEntry(text.to_string().into())),
+                },
+                word => match rgb(word) {
+                    Some(color) => spec.foreground(color),
+                    None => return Err(Error::InvalidThemeEntry(text.to_string().into())),
+                },
+            };
+        }
+        Ok(spec)
+    }
+}
+
+impl Theme {
+    pub fn dark() -> Self {
+        const HEAD: Spec = Spec::new().foreground(RUBY_RED);
+        const DESCRIPTION: Spec = Spec::new().foreground(SALMON_PINK);
+        const LINK: Spec = Spec::new().foreground(SALMON_PINK);
+        const USAGE: Spec = Spec::new().foreground(VIOLET);
+        Self::lines(HEAD, DESCRIPTION, LINK, USAGE)
+            .set(Item::Head, HEAD.bold())
+            .set(Item::Version, Spec::new().bold().foreground(RUBY_RED))
+            .set(Item::Author, HEAD.faint().italic())
+            .set(Item::Description, DESCRIPTION)
+            .set(Item::Help, Spec::new().foreground(PEACH))
+            .set(Item::Group, Spec::new().bold().foreground(OCEAN_BLUE))
+            .set(Item::Verb, Spec::new().bold().foreground(TURQUOISE))
+            .set(Item::Option, Spec::new().foreground(TURQUOISE))
+            .set(Item::Type, Spec::new().faint().foreground(TURQUOISE))
+            .set(Item::Usage, USAGE.underline())
+            .set(Item::Link, LINK.italic())
+            .set(Item::Note, Spec::new().italic().foreground(SILVER_GRAY))
+            .set(Item::Summary, Spec::new().foreground(SANDY_BROWN))
+            .set(Item::Tag, Spec::new().faint().foreground(CORAL_PINK))
+            .set(Item::Example, Spec::new().foreground(SEAFOAM_GREEN))
+            .set(Item::Strong, Spec::new().bold())
+            .set(Item::Emphasis, Spec::new().italic())
+            .set(Item::Code, Spec::new().foreground(MANGO_ORANGE))
+            .set(Item::Bullet, Spec::new().foreground(CORAL_PINK))
+            .set(Item::Block, Spec::new().foreground(SEAFOAM_GREEN))
+    }
+
+    pub fn light() -> Self {
+        const HEAD: Spec = Spec::new().foreground(BURGUNDY);
+        const DESCRIPTION: Spec = Spec::new().foreground(EARTH_BROWN);
+        const LINK: Spec = Spec::new().foreground(COBALT_BLUE);
+        const USAGE: Spec = Spec::new().foreground(INDIGO);
+        Self::lines(HEAD, DESCRIPTION, LINK, USAGE)
+            .set(Item::Head, HEAD.bold())
+            .set(Item::Version, Spec::new().bold().foreground(BURGUNDY))
+            .set(Item::Author, HEAD.italic())
+            .set(Item::Description, DESCRIPTION)
+            .set(Item::Help, Spec::new().foreground(CHARCOAL_GRAY))
+            .set(Item::Group, Spec::new().bold().foreground(COBALT_BLUE))
+            .set(Item::Verb, Spec::new().bold().foreground(TEAL))
+            .set(Item::Option, Spec::new().foreground(TEAL))
+            .set(Item::Type, Spec::new().foreground(STEEL_BLUE))
+            .set(Item::Usage, USAGE.underline())
+            .set(Item::Link, LINK.italic())
+            .set(Item::Note, Spec::new().italic().foreground(SLATE_GRAY))
+            .set(Item::Summary, Spec::new().foreground(EARTH_BROWN))
+            .set(Item::Tag, Spec::new().foreground(FOREST_GREEN))
+            .set(Item::Example, Spec::new().foreground(EMERALD_GREEN))
+            .set(Item::Strong, Spec::new().bold())
+            .set(Item::Emphasis, Spec::new().italic())
+            .set(Item::Code, Spec::new().foreground(BURGUNDY))
+            .set(Item::Bullet, Spec::new().foreground(FOREST_GREEN))
+            .set(Item::Block, Spec::new().foreground(EMERALD_GREEN))
+    }
+
+    pub fn get(&self, item: Item) -> Spec {
+        self.specs[item.index()]
+    }
+
+    pub fn set(mut self, item: Item, spec: Spec) -> Self {
+        self.specs[item.index()] = spec;
+        self
+    }
+
+    fn lines(head: Spec, description: Spec, link: Spec, usage: Spec) -> Self {
+        let mut specs = [Spec::new(); ITEMS];
+        for (line, spec) in [
+            (Line::Head, head),
+            (Line::Description, description),
+            (Line::Link, link),
+            (Line::Usage, usage),
+        ] {
+            specs[Item::Bar(line).index()] = spec;
+            specs[Item::Arrow(line).index()] = spec;
+        }
+        Self { specs }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl FromStr for Theme {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut theme = Theme::dark();
+        for entry in text.split(['\n', ';']).map(str::trim) {
+            match entry.split_once(':') {
+                Some((name, spec)) => match NAMES.iter().position(|item| *item == name.trim()) {
+                    Some(index) => theme.specs[index] = spec.parse()?,
+                    None => return Err(Error::InvalidThemeEntry(entry.to_string().into())),
+                },
+                None if entry.is_empty() => {}
+                None if entry == "dark" => theme = Theme::dark(),
+                None if entry == "light" => theme = Theme::light(),
+                None => return Err(Error::InvalidThemeEntry(entry.to_string().into())),
+            }
+        }
+        Ok(theme)
+    }
+}
+
+impl Termion {
+    fn themed() -> &'static Themed {
+        static THEMED: OnceLock<Themed> = OnceLock::new();
+        THEMED.get_or_init(|| Themed::new(Theme::default()))
+    }
+}
+
+impl Themed {
+    pub fn new(theme: Theme) -> Self {
+        Self::with(theme, None, None)
+    }
+
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    pub fn depth(self, depth: Depth) -> Self {
+        Self::with(self.theme, Some(depth), self.links)
+    }
+
+    pub fn links(self, links: Links) -> Self {
+        Self {
+            links: Some(links),
+            ..self
+        }
+    }
+
+    fn with(theme: Theme, depth: Option<Depth>, links: Option<Links>) -> Self {
+        let paint = depth.unwrap_or(Depth::True);
+        let mut begins = theme.specs.map(|spec| (Paint(spec, paint), ""));
+        for item in [Item::Type, Item::Tag, Item::Bullet].into_iter().chain(
+            [Line::Head, Line::Description, Line::Link, Line::Usage]
+                .into_iter()
+                .flat_map(|line| [Item::Bar(line), Item::Arrow(line)]),
+        ) {
+            begins[item.index()].1 = item.prefix();
+        }
+        Self {
+            theme,
+            depth,
+            links,
+            begins,
+        }
+    }
+}
+
+impl Depth {
+    pub fn from_environment(environment: impl Fn(&str) -> Option<String>) -> Self {
+        if let Some(color) = environment("COLORTERM") {
+            if matches!(color.as_str(), "truecolor" | "24bit") {
+                return Depth::True;
+            }
+        }
+        match environment("TERM") {
+            Some(term)
+                if ["truecolor", "24bit", "direct"]
+                    .iter()
+                    .any(|value| term.contains(value)) =>
+            {
+                Depth::True
+            }
+            Some(term) if term.contains("256") => Depth::Ansi256,
+            Some(_) => Depth::Ansi16,
+            None => Depth::True,
+        }
+    }
+}
+
+impl Links {
+    pub fn from_environment(environment: impl Fn(&str) -> Option<String>) -> Self {
+        let enabled = if let Some(force) = environment("FORCE_HYPERLINK") {
+            !force.is_empty() && force != "0"
+        } else if let Some(version) = environment("VTE_VERSION") {
+            version.parse::<u32>().is_ok_and(|version| version >= 5000)
+        } else {
+            ["WT_SESSION", "KONSOLE_VERSION", "DOMTERM"]
+                .iter()
+                .any(|key| environment(key).is_some())
+                || environment("TERM_PROGRAM").is_some_and(|program| {
+                    ["iTerm.app", "WezTerm", "vscode", "Hyper", "ghostty"]
+                        .contains(&program.as_str())
+                })
+                || environment("TERM").is_some_and(|term| {
+                    ["kitty", "alacritty", "foot", "wezterm", "ghostty"]
+                        .iter()
+                        .any(|name| term.contains(name))
+                })
+        };
+        if enabled {
+            Links::Enabled
+        } else {
+            Links::Disabled
+        }
+    }
+}
+
+impl ColorChoice {
+    pub fn colored(self, environment: impl Fn(&str) -> Option<String>, terminal: bool) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => {
+                if environment("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+                    false
+                } else if environment("CLICOLOR_FORCE")
+                    .is_some_and(|value| !value.is_empty() && value != "0")
+                {
+                    true
+                } else if environment("TERM").is_some_and(|value| value == "dumb") {
+                    false
+                } else {
+                    terminal
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for ColorChoice {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "auto" => Ok(ColorChoice::Auto),
+            "always" => Ok(ColorChoice::Always),
+            "never" => Ok(ColorChoice::Never),
+            _ => Err(Error::FailedToParseOptionValue(
+                Cow::Owned(text.to_string()),
+                Some(Cow::Borrowed("color")),
+                Vec::new(),
+            )),
+        }
+    }
+}
+
+fn ansi256(Rgb(red, green, blue): Rgb) -> u8 {
+    if red == green && green == blue {
+        match red {
+            0..=7 => 16,
+            249..=255 => 231,
+            gray => 232 + ((gray as u16 - 8) * 24 / 241) as u8,
+        }
+    } else {
+        let cube = |channel: u8| ((channel as u16 * 5 + 127) / 255) as u8;
+        16 + 36 * cube(red) + 6 * cube(green) + cube(blue)
+    }
+}
+
+fn ansi16(Rgb(red, green, blue): Rgb) -> u8 {
+    const PALETTE: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (205, 0, 0),
+        (0, 205, 0),
+        (205, 205, 0),
+        (0, 0, 238),
+        (205, 0, 205),
+        (0, 205, 205),
+        (229, 229, 229),
+        (127, 127, 127),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (92, 92, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ];
+    let distance = |(r, g, b): (u8, u8, u8)| {
+        [(r, red), (g, green), (b, blue)]
+            .into_iter()
+            .map(|(left, right)| (left as i32 - right as i32).pow(2))
+            .sum::<i32>()
+    };
+    (0..PALETTE.len())
+        .min_by_key(|&index| distance(PALETTE[index]))
+        .unwrap_or_default() as u8
+}
+
+fn rgb(text: &str) -> Option<Rgb> {
+    let text = text.strip_prefix('#')?;
+    if text.len() != 6 || !text.is_ascii() {
+        return None;
+    }
+    let channel = |index: usize| u8::from_str_radix(&text[index..index + 2], 16).ok();
+    Some(Rgb(channel(0)?, channel(2)?, channel(4)?))
+}
+
 pub trait Format {
     fn width(&self) -> usize;
     fn format(&self, formatter: &mut fmt::Formatter) -> fmt::Result;
@@ -153,10 +653,29 @@ impl<T: Format, const N: usize> Format for [T; N] {
     }
 }
 
+impl<T: Format> Format for Hyperlink<'_, T> {
+    #[inline]
+    fn width(&self) -> usize {
+        self.1.width()
+    }
+
+    #[inline]
+    fn format(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Some(target) => {
+                write!(formatter, "\x1b]8;;{target}\x1b\\")?;
+                self.1.format(formatter)?;
+                write!(formatter, "\x1b]8;;\x1b\\")
+            }
+            None => self.1.format(formatter),
+        }
+    }
+}
+
 impl Format for Cow<'_, str> {
     #[inline]
     fn width(&self) -> usize {
-        self.chars().count()
+        UnicodeWidthStr::width(&**self)
     }
 
     #[inline]
@@ -168,7 +687,7 @@ impl Format for Cow<'_, str> {
 impl Format for String {
     #[inline]
     fn width(&self) -> usize {
-        self.chars().count()
+        UnicodeWidthStr::width(&**self)
     }
 
     #[inline]
@@ -180,7 +699,7 @@ impl Format for String {
 impl Format for str {
     #[inline]
     fn width(&self) -> usize {
-        self.chars().count()
+        UnicodeWidthStr::width(self)
     }
 
     #[inline]
@@ -213,7 +732,63 @@ impl<C: Color> Format for Bg<C> {
     }
 }
 
-format!(char, 1);
+impl Format for Paint {
+    #[inline]
+    fn width(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn format(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let Paint(spec, depth) = self;
+        if spec.bold {
+            Bold.fmt(formatter)?;
+        }
+        if spec.faint {
+            Faint.fmt(formatter)?;
+        }
+        if spec.italic {
+            Italic.fmt(formatter)?;
+        }
+        if spec.underline {
+            Underline.fmt(formatter)?;
+        }
+        if let Some(color) = spec.foreground {
+            match depth {
+                Depth::True => Fg(color).fmt(formatter)?,
+                Depth::Ansi256 => Fg(AnsiValue(ansi256(color))).fmt(formatter)?,
+                Depth::Ansi16 => match ansi16(color) {
+                    index @ 0..=7 => write!(formatter, "\x1b[{}m", 30 + index)?,
+                    index => write!(formatter, "\x1b[{}m", 82 + index)?,
+                },
+            }
+        }
+        if let Some(color) = spec.background {
+            match depth {
+                Depth::True => Bg(color).fmt(formatter)?,
+                Depth::Ansi256 => Bg(AnsiValue(ansi256(color))).fmt(formatter)?,
+                Depth::Ansi16 => match ansi16(color) {
+                    index @ 0..=7 => write!(formatter, "\x1b[{}m", 40 + index)?,
+                    index => write!(formatter, "\x1b[{}m", 92 + index)?,
+                },
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Format for char {
+    #[inline]
+    fn width(&self) -> usize {
+        UnicodeWidthChar::width(*self).unwrap_or(0)
+    }
+
+    #[inline]
+    fn format(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, formatter)
+    }
+}
+
 format!(Reset, 0);
 format!(Bold, 0);
 format!(Faint, 0);
@@ -225,6 +800,14 @@ pub trait Style {
     fn width(&self) -> usize;
     fn begin(&self, item: Item) -> &dyn Format;
     fn end(&self, item: Item) -> &dyn Format;
+
+    fn hyperlinks(&self) -> bool {
+        false
+    }
+
+    fn resolve(&self, _: &dyn Fn(&str) -> Option<String>) -> Result<Option<Box<dyn Style>>, Error> {
+        Ok(None)
+    }
 }
 
 macro_rules! dynamic {
@@ -235,47 +818,59 @@ macro_rules! dynamic {
 impl Style for Termion {
     #[inline]
     fn indent(&self) -> usize {
-        2
+        Self::themed().indent()
     }
 
     #[inline]
     fn width(&self) -> usize {
-        terminal_size().map_or(64, |pair| pair.0 as usize - 32)
+        Self::themed().width()
     }
 
     #[inline]
     fn begin(&self, item: Item) -> &dyn Format {
-        const BAR: char = '│';
-        const ARROW: char = '>';
-        const HEAD: Fg<Rgb> = Fg(RUBY_RED);
-        const DESCRIPTION: Fg<Rgb> = Fg(SALMON_PINK);
-        const LINK: Fg<Rgb> = Fg(SALMON_PINK);
-        const USAGE: Fg<Rgb> = Fg(VIOLET);
+        Self::themed().begin(item)
+    }
 
-        match item {
-            Item::Head => dynamic!(Bold, HEAD),
-            Item::Bar(Line::Head) => dynamic!(HEAD, BAR),
-            Item::Bar(Line::Description) => dynamic!(DESCRIPTION, BAR),
-            Item::Bar(Line::Link) => dynamic!(LINK, BAR),
-            Item::Bar(Line::Usage) => dynamic!(USAGE, BAR),
-            Item::Arrow(Line::Head) => dynamic!(HEAD, ARROW),
-            Item::Arrow(Line::Description) => dynamic!(DESCRIPTION, ARROW),
-            Item::Arrow(Line::Link) => dynamic!(LINK, ARROW),
-            Item::Arrow(Line::Usage) => dynamic!(USAGE, ARROW),
-            Item::Version => dynamic!(Bold, Fg(RUBY_RED)),
-            Item::Author => dynamic!(Faint, Italic, HEAD),
-            Item::Description => dynamic!(DESCRIPTION),
-            Item::Help => dynamic!(Fg(PEACH)),
-            Item::Group => dynamic!(Bold, Fg(OCEAN_BLUE)),
-            Item::Verb => dynamic!(Bold, Fg(TURQUOISE)),
-            Item::Option => dynamic!(Fg(TURQUOISE)),
-            Item::Type => dynamic!(Faint, Fg(TURQUOISE), '<'),
-            Item::Usage => dynamic!(Underline, USAGE),
-            Item::Link => dynamic!(Italic, LINK),
-            Item::Note => dynamic!(Italic, Fg(SILVER_GRAY)),
-            Item::Summary => dynamic!(Fg(SANDY_BROWN)),
-            Item::Tag => dynamic!(Faint, Fg(CORAL_PINK), '['),
-        }
+    #[inline]
+    fn end(&self, item: Item) -> &dyn Format {
+        Self::themed().end(item)
+    }
+
+    #[inline]
+    fn hyperlinks(&self) -> bool {
+        Self::themed().hyperlinks()
+    }
+
+    fn resolve(
+        &self,
+        environment: &dyn Fn(&str) -> Option<String>,
+    ) -> Result<Option<Box<dyn Style>>, Error> {
+        let theme = match environment(THEME) {
+            Some(text) => text.parse()?,
+            None => Theme::default(),
+        };
+        Ok(Some(Box::new(Themed::with(
+            theme,
+            Some(Depth::from_environment(environment)),
+            Some(Links::from_environment(environment)),
+        ))))
+    }
+}
+
+impl Style for Themed {
+    #[inline]
+    fn indent(&self) -> usize {
+        2
+    }
+
+    #[inline]
+    fn width(&self) -> usize {
+        terminal_size().map_or(64, |pair| pair.0 as usize)
+    }
+
+    #[inline]
+    fn begin(&self, item: Item) -> &dyn Format {
+        &self.begins[item.index()]
     }
 
     #[inline]
@@ -286,6 +881,64 @@ impl Style for Termion {
             _ => dynamic!(Reset),
         }
     }
+
+    #[inline]
+    fn hyperlinks(&self) -> bool {
+        self.links == Some(Links::Enabled)
+    }
+
+    fn resolve(
+        &self,
+        environment: &dyn Fn(&str) -> Option<String>,
+    ) -> Result<Option<Box<dyn Style>>, Error> {
+        if self.depth.is_some() && self.links.is_some() {
+            return Ok(None);
+        }
+        Ok(Some(Box::new(Themed::with(
+            self.theme.clone(),
+            Some(
+                self.depth
+                    .unwrap_or_else(|| Depth::from_environment(environment)),
+            ),
+            Some(
+                self.links
+                    .unwrap_or_else(|| Links::from_environment(environment)),
+            ),
+        ))))
+    }
+}
+
+impl Bound<'_> {
+    fn style(&self) -> &dyn Style {
+        self.1.as_deref().unwrap_or(self.0)
+    }
+}
+
+impl Style for Bound<'_> {
+    #[inline]
+    fn indent(&self) -> usize {
+        self.style().indent()
+    }
+
+    #[inline]
+    fn width(&self) -> usize {
+        self.2
+    }
+
+    #[inline]
+    fn begin(&self, item: Item) -> &dyn Format {
+        self.style().begin(item)
+    }
+
+    #[inline]
+    fn end(&self, item: Item) -> &dyn Format {
+        self.style().end(item)
+    }
+
+    #[inline]
+    fn hyperlinks(&self) -> bool {
+        self.style().hyperlinks()
+    }
 }
 
 impl Style for Plain {
@@ -315,6 +968,7 @@ impl Style for Plain {
             Item::Arrow(Line::Usage) => dynamic!(ARROW),
             Item::Type => dynamic!('<'),
             Item::Tag => dynamic!('['),
+            Item::Bullet => dynamic!('-'),
             _ => dynamic!(""),
         }
     }
@@ -330,7 +984,7 @@ impl Style for Plain {
 }
 
 pub mod color {
-    use super::*;
+    pub use termion::color::Rgb;
 
     pub const OCEAN_BLUE: Rgb = Rgb(36, 113, 163);
     pub const TURQUOISE: Rgb = Rgb(64, 224, 208);
@@ -339,6 +993,7 @@ pub mod color {
     pub const CORAL_PINK: Rgb = Rgb(255, 127, 80);
     pub const VIOLET: Rgb = Rgb(238, 130, 238);
     pub const PEACH: Rgb = Rgb(255, 218, 185);
+    pub const CHARCOAL_GRAY: Rgb = Rgb(54, 69, 79);
     pub const SALMON_PINK: Rgb = Rgb(255, 145, 164);
     pub const SANDY_BROWN: Rgb = Rgb(244, 164, 96);
     pub const SUNFLOWER_YELLOW: Rgb = Rgb(255, 255, 85);
@@ -0,0 +1,1240 @@
+use checkito::*;
+use scalp::{
+    locale::{English, Heading, Label, Locale},
+    meta::Builtin,
+    style::{color::Rgb, ColorChoice, Depth, Item, Links, Spec, Style, Termion, Theme, Themed},
+    Case, Options, Parse, Parser,
+};
+use std::{borrow::Cow, error, fmt, result};
+use unicode_width::UnicodeWidthStr;
+
+type Result = result::Result<(), Box<dyn error::Error>>;
+const COUNT: usize = 100;
+
+fn help<T: fmt::Debug>(
+    parser: &Parser<impl Parse<Value = Option<T>>>,
+    arguments: impl IntoIterator<Item = impl Into<Cow<'static, str>>>,
+    environment: impl IntoIterator<Item = (impl Into<Cow<'static, str>>, impl Into<Cow<'static, str>>)>,
+) -> String {
+    match parser.parse_with(arguments, environment) {
+        Err(scalp::Error::Help(Some(help))) => help,
+        result => panic!("expected help, got {result:?}"),
+    }
+}
+
+fn environment<'a>(pairs: &'a [(&str, &str)]) -> impl Fn(&str) -> Option<String> + Copy + 'a {
+    move |key| {
+        pairs
+            .iter()
+            .find(|pair| pair.0 == key)
+            .map(|pair| pair.1.to_string())
+    }
+}
+
+#[test]
+fn man_renders_one_page_per_verb() -> Result {
+    let parser = Parser::builder()
+        .case(Case::Kebab { upper: false })
+        .name("tool")
+        .version("1.0.0")
+        .author("Boba Fett")
+        .summary("Does tool things.")
+        .help("Longer description of the tool.")
+        .option::<bool, _>(|option| {
+            option
+                .name("d")
+                .name("debug")
+                .help("Enables debug mode.")
+                .environment("TOOL_DEBUG")
+                .default(false)
+        })
+        .options(Options::common(true, true))
+        .verb(|verb| {
+            verb.name("run")
+                .summary("Runs the tool.")
+                .option(|option| option.name("s").name("settings").parse::<String>())
+        })
+        .build()?;
+    let pages = parser.man()?;
+    assert_eq!(pages.len(), 2);
+
+    let (name, page) = &pages[0];
+    assert_eq!(name, "tool.1");
+    assert!(page.starts_with(".TH \"TOOL\" \"1\" \"\" \"tool 1.0.0\"\n"));
+    assert!(page.contains(".SH NAME\ntool \\- Does tool things.\n"));
+    assert!(page.contains(".SH SYNOPSIS\ntool [OPTIONS] [COMMAND]\n"));
+    assert!(page.contains(".SH DESCRIPTION\n.PP\nLonger description of the tool.\n"));
+    assert!(page.contains(
+        ".TP\n\\fB\\-d\\fR, \\fB\\-\\-debug\\fR <\\fIboolean\\fR>\nEnables debug mode.\n"
+    ));
+    assert!(page
+        .contains(".SH COMMANDS\n.TP\n\\fBrun\\fR\nRuns the tool.\nSee \\fBtool\\-run\\fR(1).\n"));
+    assert!(page.contains(".SH ENVIRONMENT\n.TP\n\\fBTOOL_DEBUG\\fR\n"));
+    assert!(page.contains(".SH AUTHORS\nBoba Fett\n"));
+
+    let (name, page) = &pages[1];
+    assert_eq!(name, "tool-run.1");
+    assert!(page.contains(".SH NAME\ntool\\-run \\- Runs the tool.\n"));
+    assert!(page.contains(".SH SYNOPSIS\ntool run [OPTIONS]\n"));
+    assert!(page.contains("\\fB\\-\\-settings\\fR <\\fIstring\\fR>"));
+    Ok(())
+}
+
+#[test]
+fn markdown_and_html_render_reference() -> Result {
+    let parser = Parser::builder()
+        .case(Case::Kebab { upper: false })
+        .name("tool")
+        .version("1.0.0")
+        .summary("Does tool things.")
+        .option::<bool, _>(|option| {
+            option
+                .name("d")
+                .name("debug")
+                .help("Enables <debug> mode.")
+                .environment("TOOL_DEBUG")
+                .default(false)
+        })
+        .group(|group| {
+            group
+                .name("network")
+                .option(|option| option.name("port").parse::<u16>())
+        })
+        .verb(|verb| {
+            verb.name("run")
+                .summary("Runs the tool.")
+                .option(|option| option.name("s").name("settings").parse::<String>())
+        })
+        .build()?;
+
+    let markdown = parser.markdown()?;
+    assert!(markdown.contains("# tool `1.0.0`\n"));
+    assert!(markdown.contains("```text\ntool [OPTIONS] [COMMAND]\n```\n"));
+    assert!(markdown.contains("| Option | Type | Default | Environment | Valid | Description |\n"));
+    assert!(markdown.contains(
+        "| `-d`, `--debug` | `boolean` | `false` | `TOOL_DEBUG` |  | Enables &lt;debug&gt; mode. |\n"
+    ));
+    assert!(markdown.contains("## network\n"));
+    assert!(markdown.contains("| [`run`](#tool-run) | Runs the tool. |\n"));
+    assert!(markdown.contains("<a id=\"tool-run\"></a>\n\n## tool run\n"));
+    assert!(markdown.contains("| `-s`, `--settings` | `string` |"));
+
+    let html = parser.html()?;
+    assert!(html.contains("<section id=\"tool\">\n<h1>tool <code>1.0.0</code></h1>\n"));
+    assert!(html.contains("<td>Enables &lt;debug&gt; mode.</td>"));
+    assert!(html.contains("<a href=\"#tool-run\"><code>run</code></a>"));
+    assert!(html.contains("<section id=\"tool-run\">\n<h2>tool run</h2>\n"));
+    Ok(())
+}
+
+fn describe() -> result::Result<scalp::describe::Command, Box<dyn error::Error>> {
+    Ok(Parser::builder()
+        .case(Case::Kebab { upper: false })
+        .name("tool")
+        .version("1.0.0")
+        .summary("Does tool things.")
+        .option::<bool, _>(|option| {
+            option
+                .name("d")
+                .name("debug")
+                .help("Enables debug mode.")
+                .environment("TOOL_DEBUG")
+                .default(false)
+        })
+        .group(|group| {
+            group
+                .name("network")
+                .option(|option| option.name("port").parse::<u16>().require())
+        })
+        .hide()
+        .option(|option| option.name("secret").parse::<String>())
+        .show()
+        .verb(|verb| {
+            verb.name("run")
+                .summary("Runs the tool.")
+                .option(|option| option.position().many::<String, Vec<_>>())
+        })
+        .options(Options::common(true, true))
+        .build()?
+        .describe())
+}
+
+#[test]
+fn describe_exposes_the_meta_tree() -> Result {
+    let command = describe()?;
+    assert_eq!(command.path, ["tool"]);
+    assert_eq!(command.version.as_deref(), Some("1.0.0"));
+    assert_eq!(command.summary.as_deref(), Some("Does tool things."));
+
+    let debug = &command.options[0];
+    assert_eq!(debug.names, ["-d", "--debug"]);
+    assert_eq!(debug.kind.as_deref(), Some("boolean"));
+    assert_eq!(debug.default.as_deref(), Some("false"));
+    assert_eq!(debug.environment.as_deref(), Some("TOOL_DEBUG"));
+    assert!(!debug.hidden);
+
+    let port = &command.options[1];
+    assert_eq!(port.names, ["--port"]);
+    assert_eq!(port.group.as_deref(), Some("network"));
+    assert_eq!(port.tags, ["require"]);
+
+    let secret = &command.options[2];
+    assert_eq!(secret.names, ["--secret"]);
+    assert!(secret.hidden);
+    assert!(command.options[3..]
+        .iter()
+        .any(|option| option.names == ["-h", "--help"]));
+
+    let run = &command.commands[0];
+    assert_eq!(run.path, ["tool", "run"]);
+    assert_eq!(run.names, ["run"]);
+    assert_eq!(run.options[0].position, Some(0));
+    assert_eq!(run.options[0].many, Some(Some(1)));
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn describe_serializes_to_json() -> Result {
+    let json = serde_json::to_value(describe()?)?;
+    assert_eq!(json["path"][0], "tool");
+    assert_eq!(json["options"][0]["names"][1], "--debug");
+    assert_eq!(json["options"][2]["hidden"], true);
+    assert_eq!(json["commands"][0]["names"][0], "run");
+    Ok(())
+}
+
+#[test]
+fn usage_synthesizes_positionals_and_commands() -> Result {
+    let parser = Parser::builder()
+        .case(Case::Kebab { upper: false })
+        .name("tool")
+        .style(scalp::style::Plain)
+        .option(|option| option.name("target").parse::<String>().require())
+        .option::<bool, _>(|option| option.name("d").name("debug").default(false))
+        .option(|option| {
+            option
+                .position()
+                .value_name("source")
+                .parse::<String>()
+                .require()
+        })
+        .option(|option| {
+            option
+                .name("files")
+                .position()
+                .parse::<String>()
+                .many::<_, Vec<_>>()
+        })
+        .verb(|verb| verb.name("run"))
+        .options(Options::common(true, true))
+        .build()?;
+    let help = help(&parser, ["--help"], [("", "")]);
+    assert!(help.contains("Usage: tool [OPTIONS] --target <string> <source> [files]... [COMMAND]"));
+    Ok(())
+}
+
+#[test]
+fn help_accepts_a_verb_option_or_topic() -> Result {
+    let parser = Parser::builder()
+        .case(Case::Kebab { upper: false })
+        .name("tool")
+        .style(scalp::style::Plain)
+        .topic("config", "Configuration is read from 'tool.toml'.")
+        .option::<bool, _>(|option| {
+            option
+                .name("debug")
+                .help("Enables debug mode.")
+                .default(false)
+        })
+        .verb(|verb| {
+            verb.name("run")
+                .help("Runs the tool.")
+                .verb(|verb| verb.name("build").help("Builds before running."))
+                .options(Options::common(true, true))
+        })
+        .options(Options::common(true, true))
+        .build()?;
+    let help = |arguments: &[&'static str]| help(&parser, arguments.to_vec(), [("", "")]);
+
+    let build = help(&["--help", "run", "build"]);
+    assert!(build.contains("Usage: tool run build"));
+    assert!(build.contains("Builds before running."));
+    assert!(!build.contains("Enables debug mode."));
+
+    let debug = help(&["--help", "--debug"]);
+    assert!(debug.contains("Enables debug mode."));
+    assert!(!debug.contains("Runs the tool."));
+
+    let config = help(&["--help", "config"]);
+    assert!(config.contains("config"));
+    assert!(config.contains("Configuration is read from 'tool.toml'."));
+
+    let root = help(&["--help"]);
+    assert_eq!(help(&["--help", "conifg"]), root);
+    assert_eq!(help(&["--help", "run", "garbage"]), root);
+    let run = help(&["run", "--help"]);
+    assert!(run.contains("Runs the tool."));
+    assert_eq!(help(&["run", "--help", "extra"]), run);
+    Ok(())
+}
+
+#[test]
+fn help_search_lists_matches_with_paths() -> Result {
+    let parser = Parser::builder()
+        .case(Case::Kebab { upper: false })
+        .name("tool")
+        .style(scalp::style::Plain)
+        .option::<bool, _>(|option| {
+            option
+                .name("verbose")
+                .help("Prints more details.")
+                .default(false)
+        })
+        .verb(|verb| {
+            verb.name("remote")
+                .summary("Manages remotes.")
+                .verb(|verb| {
+                    verb.name("prune")
+                        .summary("Removes stale branches.")
+                        .option::<bool, _>(|option| {
+                            option
+                                .name("dry-run")
+                                .help("Lists the branches without removing them.")
+                                .default(false)
+                        })
+                })
+        })
+        .options(Options::all(false, true))
+        .options([Options::search(false, true)])
+        .build()?;
+    let search = |keyword: &'static str| help(&parser, ["--help-search", keyword], [("", "")]);
+
+    let branch = search("branch");
+    assert!(branch.contains("Results for 'branch'"));
+    assert!(branch.contains("  tool remote prune            Removes stale branches.\n"));
+    assert!(
+        branch.contains("tool remote prune --dry-run  Lists the branches without removing them.")
+    );
+    assert!(!branch.contains("--verbose"));
+
+    let typo = search("verbos");
+    assert!(typo.contains("tool --verbose"));
+    let fuzzy = search("detials");
+    assert!(fuzzy.contains("tool --verbose"));
+    assert!(search("zebra").contains("No matches."));
+    Ok(())
+}
+
+#[test]
+fn builtin_options_can_be_renamed_described_and_hidden() -> Result {
+    let parser = Parser::builder()
+        .case(Case::Kebab { upper: false })
+        .name("tool")
+        .version("1.0.0")
+        .style(scalp::style::Plain)
+        .option::<bool, _>(|option| option.name("v").name("verbose").default(false))
+        .options([
+            Options::version(true, true),
+            Options::help(true, true),
+            Options::license(false, true),
+        ])
+        .builtin(Builtin::Version, |option| option.name("V"))
+        .builtin(Builtin::Help, |option| {
+            option.name("Usage Help").help("Shows the usage.")
+        })
+        .builtin(Builtin::License, |option| option.hide())
+        .build()?;
+    let help = help(&parser, ["--usage-help"], [("", "")]);
+    assert!(help.contains("-v  --verbose"));
+    assert!(help.contains("-V  --version"));
+    assert!(help.contains("-h  --usage-help             Shows the usage."));
+    assert!(!help.contains("--license"));
+    assert!(matches!(
+        parser.parse_with(["-V"], [("", "")]),
+        Err(scalp::Error::Version(Some(_)))
+    ));
+    assert!(matches!(
+        parser.parse_with(["--license"], [("", "")]),
+        Err(scalp::Error::License(_))
+    ));
+    assert_eq!(parser.parse_with(["-v"], [("", "")])?, (true,));
+
+    let parser = Parser::builder()
+        .name("tool")
+        .style(scalp::style::Plain)
+        .options([Options::help(false, true), Options::version(true, false)])
+        .builtin(Builtin::Help, |option| option.name("h"))
+        .builtin(Builtin::Version, |option| {
+            option.name("version").details("Includes the release date.")
+        })
+        .build()?;
+    let brief = self::help(&parser, ["-h"], [("", "")]);
+    assert!(brief.contains("-h  --help"));
+    assert!(brief.contains("-v  --version"));
+    assert!(!brief.contains("Includes the release date."));
+    assert!(self::help(&parser, ["--help"], [("", "")]).contains("Includes the release date."));
+    assert!(matches!(
+        parser.parse_with(["--version"], [("", "")]),
+        Err(scalp::Error::Version(_))
+    ));
+
+    let suggestions = |weight: usize| -> result::Result<Vec<String>, Box<dyn error::Error>> {
+        let parser = Parser::builder()
+            .case(Case::Kebab { upper: false })
+            .options([Options::help(false, true)])
+            .builtin(Builtin::Help, |option| option.weight(weight))
+            .option::<bool, _>(|option| option.name("hello").weight(5).default(false))
+            .build()?;
+        match parser.parse_with(["--helo"], [("", "")]) {
+            Err(scalp::Error::UnrecognizedArgument(_, suggestions)) => {
+                Ok(suggestions.iter().map(|pair| pair.0.to_string()).collect())
+            }
+            result => panic!("expected suggestions, got {result:?}"),
+        }
+    };
+    assert_eq!(suggestions(0)?, ["--hello", "--help"]);
+    assert_eq!(suggestions(9)?, ["--help", "--hello"]);
+
+    let invalid = |builder: scalp::Builder<_, _>| match builder.build() {
+        Err(error) => error,
+        Ok(_) => panic!("expected an invalid built-in option"),
+    };
+    let builder = || Parser::builder().options([Options::help(true, true)]);
+    for error in [
+        invalid(builder().builtin(Builtin::Help, |option| option.valid("x"))),
+        invalid(builder().builtin(Builtin::Help, |option| option.secret())),
+        invalid(builder().builtin(Builtin::Help, |option| option.weight(1).position())),
+    ] {
+        assert_eq!(error, scalp::Error::InvalidBuiltinOption(Builtin::Help));
+        assert!(error.to_string().contains("'help'"));
+    }
+    Ok(())
+}
+
+#[test]
+fn locale_translates_builtin_messages_and_errors() -> Result {
+    struct German;
+
+    impl Locale for German {
+        fn language(&self) -> &str {
+            "de"
+        }
+
+        fn help(&self, builtin: Builtin) -> Cow<'static, str> {
+            match builtin {
+                Builtin::Help => "Zeigt diese Hilfe an.".into(),
+                Builtin::Version => "Zeigt die Version an.".into(),
+                _ => English.help(builtin),
+            }
+        }
+
+        fn usage(&self) -> Cow<'static, str> {
+            "Verwendung:".into()
+        }
+
+        fn heading(&self, heading: Heading) -> Cow<'static, str> {
+            match heading {
+                Heading::Options => "Optionen".into(),
+                Heading::Commands => "Befehle".into(),
+                Heading::Examples => "Beispiele".into(),
+                Heading::Synopsis => "Übersicht".into(),
+                Heading::Description => "Beschreibung".into(),
+                Heading::Default => "Standard".into(),
+                _ => English.heading(heading),
+            }
+        }
+
+        fn label(&self, label: Label) -> Cow<'static, str> {
+            match label {
+                Label::Options => "OPTIONEN".into(),
+                Label::Command => "BEFEHL".into(),
+                Label::Default => "standard".into(),
+                Label::Valid => "gültig".into(),
+            }
+        }
+
+        fn error(&self, error: &scalp::Error, formatter: &mut fmt::Formatter) -> fmt::Result {
+            match error {
+                scalp::Error::UnrecognizedArgument(argument, _) => {
+                    write!(formatter, "Unbekanntes Argument '{argument}'.")
+                }
+                error => write!(formatter, "{error}"),
+            }
+        }
+    }
+
+    let parser = Parser::builder()
+        .case(Case::Kebab { upper: false })
+        .locale(German)
+        .name("tool")
+        .version("1.0.0")
+        .style(scalp::style::Plain)
+        .option::<bool, _>(|option| option.name("v").name("verbose").default(false))
+        .verb(|verb| verb.name("run").help("Runs the tool."))
+        .example("tool run", "Runs once.")
+        .build()?;
+    let help = help(&parser, ["--help"], [("", "")]);
+    assert!(help.contains("Verwendung: tool [OPTIONEN] [BEFEHL]"));
+    assert!(help.contains("standard: false"));
+    assert!(!help.contains("default: "));
+    assert!(help.contains("Zeigt diese Hilfe an."));
+    assert!(help.contains("Zeigt die Version an."));
+    assert!(!help.contains("Displays this help message."));
+
+    let man = parser.man()?.remove(0).1;
+    for section in [
+        ".SH ÜBERSICHT",
+        ".SH OPTIONEN",
+        ".SH BEFEHLE",
+        ".SH BEISPIELE",
+    ] {
+        assert!(man.contains(section), "missing '{section}' in {man}");
+    }
+    assert!(man.contains("tool [OPTIONEN] [BEFEHL]"));
+    assert!(!man.contains(".SH OPTIONS") && !man.contains(".SH SYNOPSIS"));
+    let markdown = parser.markdown()?;
+    for heading in ["## Optionen", "## Befehle", "## Beispiele", "| Standard |"] {
+        assert!(
+            markdown.contains(heading),
+            "missing '{heading}' in {markdown}"
+        );
+    }
+    assert!(!markdown.contains("Options") && !markdown.contains("| Default |"));
+    let html = parser.html()?;
+    for heading in [">Optionen<", ">Befehle<", ">Beispiele<", ">Standard<"] {
+        assert!(html.contains(heading), "missing '{heading}' in {html}");
+    }
+    assert!(!html.contains(">Options<") && !html.contains(">Commands<"));
+
+    let error = parser.parse_with(["-v", "a"], [("", "")]).unwrap_err();
+    assert_eq!(
+        parser.localize(&error).to_string(),
+        "Unbekanntes Argument 'a'."
+    );
+    assert_eq!(error.to_string(), "Unrecognized argument 'a'.");
+    assert_eq!(
+        error.localize(&English).to_string(),
+        "Unrecognized argument 'a'."
+    );
+    Ok(())
+}
+
+#[test]
+fn termion_themes_color_items_from_presets_and_text() -> Result {
+    let theme: Theme = "light; summary: #102030 on #ffffff italic; verb: bold".parse()?;
+    assert_eq!(
+        theme.get(Item::Summary),
+        Spec::new()
+            .foreground(Rgb(0x10, 0x20, 0x30))
+            .background(Rgb(0xff, 0xff, 0xff))
+            .italic()
+    );
+    assert_eq!(theme.get(Item::Verb), Spec::new().bold());
+    assert_eq!(theme.get(Item::Head), Theme::light().get(Item::Head));
+    assert_ne!(
+        Theme::light().get(Item::Help),
+        Theme::dark().get(Item::Help)
+    );
+    assert!("help: #12345".parse::<Theme>().is_err());
+    assert!("sparkles: bold".parse::<Theme>().is_err());
+
+    let parser = Parser::builder()
+        .name("tool")
+        .style(Themed::new(theme).depth(Depth::True))
+        .color(ColorChoice::Always)
+        .option::<bool, _>(|option| option.name("v").help("Prints more.").default(false))
+        .build()?;
+    let help = help(&parser, ["--help"], [("", "")]);
+    assert!(help.contains("\x1b[3m\x1b[38;2;16;32;48m\x1b[48;2;255;255;255mPrints more."));
+    Ok(())
+}
+
+#[test]
+fn termion_downgrades_colors_to_the_detected_depth() -> Result {
+    assert_eq!(
+        Depth::from_environment(environment(&[
+            ("COLORTERM", "truecolor"),
+            ("TERM", "xterm")
+        ])),
+        Depth::True
+    );
+    assert_eq!(
+        Depth::from_environment(environment(&[("TERM", "tmux-256color")])),
+        Depth::Ansi256
+    );
+    assert_eq!(
+        Depth::from_environment(environment(&[("TERM", "xterm")])),
+        Depth::Ansi16
+    );
+
+    regex!("[a-z]{1,8}-256color").check(COUNT, |term| {
+        let depth = Depth::from_environment(environment(&[("TERM", term)]));
+        prove!(depth == Depth::Ansi256)
+    })?;
+    regex!("[a-z]{1,8}").check(COUNT, |term| {
+        let depth = Depth::from_environment(environment(&[("COLORTERM", "24bit"), ("TERM", term)]));
+        prove!(depth == Depth::True)
+    })?;
+
+    let painted = |depth, foreground| -> result::Result<String, Box<dyn error::Error>> {
+        let theme = Theme::dark().set(
+            Item::Summary,
+            Spec::new().foreground(foreground).background(Rgb(0, 0, 0)),
+        );
+        let parser = Parser::builder()
+            .name("tool")
+            .style(Themed::new(theme).depth(depth))
+            .color(ColorChoice::Always)
+            .option::<bool, _>(|option| option.name("v").help("Prints more.").default(false))
+            .build()?;
+        Ok(help(&parser, ["--help"], [("", "")]))
+    };
+    let help = |depth| painted(depth, Rgb(255, 0, 0));
+    assert!(help(Depth::True)?.contains("\x1b[38;2;255;0;0m\x1b[48;2;0;0;0mPrints more."));
+    assert!(help(Depth::Ansi256)?.contains("\x1b[38;5;196m\x1b[48;5;16mPrints more."));
+    let ansi16 = help(Depth::Ansi16)?;
+    assert!(ansi16.contains("\x1b[91m\x1b[40mPrints more."));
+    assert!(!ansi16.contains("38;2;"));
+
+    let strip = |help: String| {
+        let mut plain = String::new();
+        let mut escape = false;
+        for character in help.chars() {
+            match character {
+                '\x1b' => escape = true,
+                'm' if escape => escape = false,
+                _ if escape => {}
+                character => plain.push(character),
+            }
+        }
+        plain
+    };
+    assert_eq!(strip(help(Depth::True)?), strip(help(Depth::Ansi16)?));
+    assert_eq!(strip(help(Depth::True)?), strip(help(Depth::Ansi256)?));
+
+    (u8::generator(), u8::generator(), u8::generator()).check(COUNT, |(red, green, blue)| {
+        let foreground = Rgb(*red, *green, *blue);
+        let [full, ansi256, ansi16] = [Depth::True, Depth::Ansi256, Depth::Ansi16]
+            .map(|depth| painted(depth, foreground).unwrap());
+        prove!(
+            full.contains(&format!("\x1b[38;2;{red};{green};{blue}m"))
+                && ansi256.contains("\x1b[38;5;")
+                && !ansi256.contains("38;2;")
+                && !ansi16.contains("38;2;")
+                && !ansi16.contains("38;5;")
+                && strip(full.clone()) == strip(ansi256)
+                && strip(full) == strip(ansi16)
+        )
+    })?;
+    Ok(())
+}
+
+#[test]
+fn color_choice_switches_between_termion_and_plain() -> Result {
+    let parser = Parser::builder()
+        .name("tool")
+        .style(Themed::new(Theme::dark()).depth(Depth::True))
+        .options([Options::help(true, true), Options::color(false, true)])
+        .option::<bool, _>(|option| option.name("v").help("Prints more.").default(false))
+        .build()?;
+    let help = |arguments: &[&'static str], environment: &[(&'static str, &'static str)]| {
+        help(&parser, arguments.to_vec(), environment.to_vec())
+    };
+    let colored = |help: String| help.contains('\x1b');
+
+    assert!(colored(help(&["--help"], &[("CLICOLOR_FORCE", "1")])));
+    assert!(!colored(help(
+        &["--help"],
+        &[("CLICOLOR_FORCE", "1"), ("NO_COLOR", "1")]
+    )));
+    assert!(!colored(help(&["--help"], &[("TERM", "dumb")])));
+    assert!(!colored(help(
+        &["--color", "never", "--help"],
+        &[("CLICOLOR_FORCE", "1")]
+    )));
+    assert!(!colored(help(
+        &["--help", "--color", "never"],
+        &[("CLICOLOR_FORCE", "1")]
+    )));
+    assert!(colored(help(
+        &["--help", "--color", "always"],
+        &[("NO_COLOR", "1")]
+    )));
+    for (choice, environment, expected) in [
+        ("--color=always", ("NO_COLOR", "1"), true),
+        ("--color=never", ("CLICOLOR_FORCE", "1"), false),
+        ("--color=auto", ("CLICOLOR_FORCE", "1"), true),
+        ("--color=auto", ("NO_COLOR", "1"), false),
+    ] {
+        assert_eq!(colored(help(&["--help", choice], &[environment])), expected);
+        assert_eq!(colored(help(&[choice, "--help"], &[environment])), expected);
+    }
+    let plain = help(&["--help", "--color", "never"], &[]);
+    assert!(plain.contains("--color  <color>"));
+    assert!(plain.contains("Chooses when to color the output (auto, always or never)."));
+
+    assert_eq!(
+        parser.parse_with(["--color", "always", "-v"], [("", "")])?,
+        (true,)
+    );
+    assert_eq!(
+        parser.parse_with(["--color=never", "-v"], [("", "")])?,
+        (true,)
+    );
+    assert!(matches!(
+        parser.parse_with(["--color=rainbow"], [("", "")]),
+        Err(scalp::Error::FailedToParseOptionValue(..))
+    ));
+    assert!(matches!(
+        parser.parse_with(["--color", "rainbow"], [("", "")]),
+        Err(scalp::Error::FailedToParseOptionValue(..))
+    ));
+    assert!(matches!(
+        parser.parse_with(["--color"], [("", "")]),
+        Err(scalp::Error::MissingOptionValue(..))
+    ));
+
+    let parser = Parser::builder()
+        .name("tool")
+        .summary("Does things.")
+        .style(Termion)
+        .options([Options::help(true, true)])
+        .build()?;
+    assert!(self::help(&parser, ["--help"], [("CLICOLOR_FORCE", "1")]).contains('\x1b'));
+    let theme = [("CLICOLOR_FORCE", "1"), ("SCALP_THEME", "summary: #102030")];
+    assert!(self::help(&parser, ["--help"], theme).contains("\x1b[38;2;16;32;48m"));
+    let xterm = [theme[0], theme[1], ("TERM", "xterm")];
+    assert!(!self::help(&parser, ["--help"], xterm).contains("38;2;"));
+    assert!(matches!(
+        parser.parse_with(
+            ["--help"],
+            [theme[0], ("SCALP_THEME", "summary: chartreuse")]
+        ),
+        Err(scalp::Error::InvalidThemeEntry(..))
+    ));
+
+    let parser = Parser::builder()
+        .name("tool")
+        .options(Options::all(true, true))
+        .option::<String, _>(|option| option.name("C").name("color").default(String::from("red")))
+        .build()?;
+    assert_eq!(
+        parser.parse_with(["-C", "blue"], [("", "")])?,
+        ("blue".to_string(),)
+    );
+
+    let environment = environment(&[("TERM", "xterm")]);
+    assert!(ColorChoice::Auto.colored(environment, true));
+    assert!(!ColorChoice::Auto.colored(environment, false));
+    assert!(!ColorChoice::Never.colored(environment, true));
+    assert!(ColorChoice::Always.colored(environment, false));
+    Ok(())
+}
+
+#[test]
+fn help_wrapping_uses_display_width() -> Result {
+    let parser = Parser::builder()
+        .name("tool")
+        .style(scalp::style::Plain)
+        .option::<String, _>(|option| {
+            option
+                .name("name")
+                .help("名前を設定します。".repeat(5))
+                .default("名無しの権兵衛".repeat(2))
+        })
+        .help("漢字".repeat(80))
+        .build()?;
+    let help = help(&parser, ["--help"], [("", "")]);
+    for line in help.lines() {
+        assert!(line.width() <= 96, "line '{line}' is too wide");
+    }
+    assert!(help.lines().filter(|line| line.contains("漢字")).count() >= 3);
+    assert!(help.contains("[default: \"名無しの権兵衛名無しの権兵衛\"]"));
+    Ok(())
+}
+
+#[test]
+fn help_layout_responds_to_columns_and_width_bounds() -> Result {
+    let parser = |minimum, maximum| {
+        Parser::builder()
+            .name("tool")
+            .style(scalp::style::Plain)
+            .minimum_width(minimum)
+            .maximum_width(maximum)
+            .option::<String, _>(|option| {
+                option
+                    .name("o")
+                    .name("output-directory")
+                    .help("Writes every generated artifact into this directory.")
+                    .default("target")
+            })
+            .build()
+    };
+    let help = |minimum,
+                maximum,
+                columns: &'static str|
+     -> result::Result<String, Box<dyn error::Error>> {
+        Ok(help(
+            &parser(minimum, maximum)?,
+            ["--help"],
+            [("COLUMNS", columns)],
+        ))
+    };
+    let widest = |help: &str| help.lines().map(|line| line.trim_end().width()).max();
+
+    let wide = help(0, usize::MAX, "200")?;
+    assert!(wide.contains(
+        "--output-directory  <string>  Writes every generated artifact into this directory."
+    ));
+
+    let narrow = help(0, usize::MAX, "40")?;
+    assert!(widest(&narrow) <= Some(40));
+    assert!(
+        narrow.contains("-o  --output-directory  <string>  \n  Writes every generated artifact")
+    );
+
+    let unset = help(0, usize::MAX, "0")?;
+    assert!(unset.contains(
+        "--output-directory  <string>  Writes every generated artifact into this directory."
+    ));
+    let tiny = help(0, usize::MAX, "10")?;
+    assert!(widest(&tiny) > Some(10));
+    assert!(widest(&tiny) <= Some(40));
+
+    let bounded = help(0, 60, "200")?;
+    assert!(widest(&bounded) <= Some(60));
+    let floored = help(60, usize::MAX, "20")?;
+    assert!(widest(&floored) <= Some(60));
+    assert!(widest(&floored) > Some(40));
+    Ok(())
+}
+
+#[test]
+fn help_fills_wide_terminals_up_to_the_maximum_width() -> Result {
+    let parser = |maximum| {
+        Parser::builder()
+            .name("tool")
+            .style(scalp::style::Plain)
+            .maximum_width(maximum)
+            .option::<String, _>(|option| {
+                option
+                    .name("o")
+                    .name("output-directory")
+                    .help("Writes every generated artifact into this directory. ".repeat(6))
+            })
+            .build()
+    };
+    let widest = |maximum| -> result::Result<usize, Box<dyn error::Error>> {
+        let help = help(&parser(maximum)?, ["--help"], [("COLUMNS", "300")]);
+        Ok(help
+            .lines()
+            .map(|line| line.trim_end().width())
+            .max()
+            .unwrap_or_default())
+    };
+
+    assert!(widest(usize::MAX)? > 268);
+    assert!(widest(usize::MAX)? <= 300);
+    assert!(widest(120)? <= 120);
+    assert!(widest(120)? > 100);
+    assert_eq!(
+        Themed::new(Theme::dark()).width(),
+        termion::terminal_size().map_or(64, |(width, _)| usize::from(width))
+    );
+    Ok(())
+}
+
+#[test]
+fn examples_render_in_help_and_docs_and_are_checked() -> Result {
+    let builder = || {
+        Parser::builder()
+            .case(Case::Kebab { upper: false })
+            .name("tool")
+            .style(scalp::style::Plain)
+            .options(Options::common(true, true))
+            .example("tool --help", "Shows the help.")
+            .verb(|verb| {
+                verb.name("run")
+                    .summary("Runs the tool.")
+                    .option(|option| option.name("s").name("settings").parse::<String>())
+                    .example("tool run -s 'my settings.json'", "Runs with settings.")
+            })
+    };
+    let parser = builder().build()?;
+    parser.check_examples()?;
+
+    let run = help(&parser, ["run", "--help"], [("", "")]);
+    assert!(
+        run.contains("\nExamples:\n  Runs with settings.\n    tool run -s 'my settings.json'\n")
+    );
+    assert!(!run.contains("Shows the help."));
+
+    let markdown = parser.markdown()?;
+    assert!(markdown.contains("Examples"));
+    assert!(
+        markdown.contains("Runs with settings.\n\n```text\ntool run -s 'my settings.json'\n```")
+    );
+    let pages = parser.man()?;
+    assert!(pages[0]
+        .1
+        .contains(".SH EXAMPLES\n.TP\n\\fBtool \\-\\-help\\fR\nShows the help.\n"));
+    assert_eq!(
+        parser.describe().commands[0].examples,
+        [(
+            "tool run -s 'my settings.json'".to_string(),
+            "Runs with settings.".to_string()
+        )]
+    );
+
+    let stale = builder().example("tool run --verbose", "").build()?;
+    match stale.check_examples() {
+        Err(scalp::Error::InvalidExample(example, error)) => {
+            assert_eq!(example, "tool run --verbose");
+            assert!(matches!(*error, scalp::Error::UnrecognizedArgument(..)));
+        }
+        result => panic!("expected an invalid example, got {result:?}"),
+    }
+
+    (regex!("[a-z]{3,8}"), regex!("[A-Z][a-z]{2,12}\\.")).check(COUNT, |(name, text)| {
+        let parser = |example: String| {
+            Parser::builder()
+                .case(Case::Same)
+                .name("tool")
+                .style(scalp::style::Plain)
+                .option(|option| option.name(format!("opt-{name}")).parse::<String>())
+                .example(example, text.clone())
+                .build()
+                .unwrap()
+        };
+        let valid = parser(format!("tool --opt-{name} value"));
+        let stale = parser(format!("tool --opt-{name}-stale value"));
+        let help = help(&valid, ["--help"], [("", "")]);
+        prove!(
+            valid.check_examples().is_ok()
+                && help.contains(&format!("  {text}\n    tool --opt-{name} value\n"))
+                && matches!(
+                    stale.check_examples(),
+                    Err(scalp::Error::InvalidExample(example, _)) if example.contains("-stale")
+                )
+        )
+    })?;
+    Ok(())
+}
+
+#[test]
+fn short_help_is_compact_and_long_help_includes_details() -> Result {
+    let parser = Parser::builder()
+        .case(Case::Kebab { upper: false })
+        .name("tool")
+        .style(scalp::style::Plain)
+        .help("Does things.")
+        .details("Reads the configuration before doing anything else.")
+        .note("Run with care.")
+        .options(Options::common(true, true))
+        .example("tool --help", "Shows the help.")
+        .option::<u8, _>(|option| {
+            option
+                .name("l")
+                .name("level")
+                .help("Sets the level.\nHigher levels are louder.")
+                .details("Levels above 3 also print timings.")
+                .default(1)
+        })
+        .build()?;
+
+    let brief = help(&parser, ["-h"], [("", "")]);
+    assert!(brief.contains("Does things."));
+    assert!(brief.contains("Sets the level.\n"));
+    assert!(!brief.contains("Higher levels are louder."));
+    assert!(!brief.contains("Reads the configuration"));
+    assert!(!brief.contains("Levels above 3"));
+    assert!(!brief.contains("Run with care."));
+    assert!(!brief.contains("Examples:"));
+    assert!(!brief.contains("default: 1"));
+
+    let full = help(&parser, ["--help"], [("", "")]);
+    assert!(full.contains("Reads the configuration before doing anything else."));
+    assert!(full.contains("Higher levels are louder."));
+    assert!(full.contains("Levels above 3 also print timings."));
+    assert!(full.contains("Run with care."));
+    assert!(full.contains("Examples:"));
+    assert!(full.contains("default: 1"));
+
+    let description = parser.describe();
+    let level = description
+        .options
+        .iter()
+        .find(|option| option.names.contains(&"--level".to_string()));
+    assert_eq!(
+        level.map(|option| &option.details[..]),
+        Some(&["Levels above 3 also print timings.".to_string()][..])
+    );
+
+    regex!("[a-z]{3,10}")
+        .array::<3>()
+        .check(COUNT, |[first, second, detail]| {
+            let parser = Parser::builder()
+                .case(Case::Kebab { upper: false })
+                .name("tool")
+                .style(scalp::style::Plain)
+                .options(Options::common(true, true))
+                .option::<u8, _>(|option| {
+                    option
+                        .name("l")
+                        .help(format!("First {first}.\nSecond {second}."))
+                        .details(format!("Detail {detail}."))
+                })
+                .build()
+                .unwrap();
+            let brief = help(&parser, ["-h"], [("", "")]);
+            let full = help(&parser, ["--help"], [("", "")]);
+            prove!(
+                brief.contains(&format!("First {first}."))
+                    && !brief.contains(&format!("Second {second}."))
+                    && !brief.contains(&format!("Detail {detail}."))
+                    && full.contains(&format!("First {first}."))
+                    && full.contains(&format!("Second {second}."))
+                    && full.contains(&format!("Detail {detail}."))
+            )
+        })?;
+    Ok(())
+}
+
+#[test]
+fn verbose_version_includes_build_metadata() -> Result {
+    let parser = Parser::builder()
+        .case(Case::Kebab { upper: false })
+        .name("tool")
+        .version("1.0.0")
+        .metadata("commit", "0123456789ab-dirty")
+        .metadata("target", "x86_64-unknown-linux-gnu")
+        .metadata("features", "")
+        .pipe(scalp::metadata!())
+        .options([Options::version(true, true), Options::verbose(true, true)])
+        .build()?;
+    let version =
+        |arguments: &[&'static str]| match parser.parse_with(arguments.to_vec(), [("", "")]) {
+            Err(scalp::Error::Version(Some(version))) => version,
+            result => panic!("expected a version, got {result:?}"),
+        };
+    assert_eq!(version(&["--version"]), "1.0.0");
+    let verbose = "1.0.0\ncommit: 0123456789ab-dirty\ntarget: x86_64-unknown-linux-gnu";
+    assert_eq!(version(&["--version", "--verbose"]), verbose);
+    assert_eq!(version(&["--verbose", "--version"]), verbose);
+    assert_eq!(version(&["-vV"]), verbose);
+    assert!(matches!(
+        parser.parse_with(["-V"], [("", "")]),
+        Err(scalp::Error::UnrecognizedArgument(..))
+    ));
+    assert!(matches!(
+        parser.parse_with(["--verbose"], [("", "")]),
+        Err(scalp::Error::UnrecognizedArgument(..))
+    ));
+
+    let metadata = scalp::metadata::collect([
+        ("TARGET".to_string(), "wasm32-unknown-unknown".to_string()),
+        ("SOURCE_DATE_EPOCH".to_string(), "1709251200".to_string()),
+        ("CARGO_FEATURE_SERDE".to_string(), "1".to_string()),
+        ("CARGO_FEATURE_NO_STD".to_string(), "1".to_string()),
+    ]);
+    let get = |key: &str| {
+        metadata
+            .iter()
+            .find(|pair| pair.0 == key)
+            .map(|pair| pair.1.as_str())
+    };
+    assert_eq!(get(scalp::metadata::DATE), Some("2024-03-01"));
+    assert_eq!(get(scalp::metadata::TARGET), Some("wasm32-unknown-unknown"));
+    assert_eq!(get(scalp::metadata::FEATURES), Some("no-std, serde"));
+
+    let directory = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+    let watched = scalp::metadata::watch(directory);
+    if directory.join(".git").is_dir() {
+        for file in ["HEAD", "index"] {
+            assert!(watched.iter().any(|path| path.ends_with(file)));
+        }
+        assert!(watched
+            .iter()
+            .any(|path| path.starts_with(directory.join(".git/refs"))
+                || path.ends_with("packed-refs")));
+    }
+    assert!(watched.iter().all(|path| path.exists()));
+    Ok(())
+}
+
+#[test]
+fn markup_is_styled_by_termion_and_stripped_by_plain() -> Result {
+    let builder = || {
+        Parser::builder()
+            .case(Case::Kebab { upper: false })
+            .name("tool")
+            .minimum_width(60)
+            .maximum_width(60)
+            .option::<String, _>(|option| {
+                option
+                    .name("f")
+                    .name("format")
+                    .help("Chooses the **output** format, like `json` or *text*.")
+                    .details(
+                        "Supported formats:\n\
+                         - `json` writes one object per line and keeps going with many more words\n\
+                         - `text` writes aligned columns\n\
+                         ```\n\
+                         tool --format json  |  jq\n\
+                         ```",
+                    )
+            })
+            .option::<String, _>(|option| {
+                option
+                    .name("s")
+                    .name("scale")
+                    .help("Scales by 2*3*4 or by \\*factor\\*.")
+            })
+    };
+    let help = |parser: Parser<_>| help(&parser, ["--help"], [("COLUMNS", "60")]);
+
+    let raw = help(builder().style(scalp::style::Plain).build()?);
+    assert!(raw.contains("Chooses the **output** format"));
+    assert!(raw.contains("- `text` writes aligned columns\n"));
+    assert!(raw.contains("\n                        ```"));
+    assert!(raw.contains("Scales by 2*3*4 or by \\*factor\\*."));
+
+    let plain = help(builder().markup(true).style(scalp::style::Plain).build()?);
+    assert!(plain.contains("  Chooses the output format, like json\n"));
+    assert!(plain.contains("- json writes one object per line\n"));
+    assert!(plain.contains("\n                          and keeps going with many more\n"));
+    assert!(plain.contains("\n                        tool --format json  |  jq"));
+    assert!(plain.contains("Scales by 2*3*4 or by *factor*."));
+    assert!(!plain.contains('`') && !plain.contains("**") && !plain.contains("*text*"));
+
+    let parser = builder().markup(true).build()?;
+    let man = parser.man()?.remove(0).1;
+    assert!(man.contains("Chooses the \\fBoutput\\fR format, like \\fBjson\\fR or \\fItext\\fR."));
+    let markdown = parser.markdown()?;
+    assert!(markdown.contains("Chooses the **output** format, like `json` or *text*."));
+    assert!(markdown.contains("Scales by 2\\*3\\*4 or by \\*factor\\*."));
+    let html = parser.html()?;
+    assert!(html.contains("<strong>output</strong>"));
+    assert!(html.contains("<code>json</code> or <em>text</em>"));
+    assert!(builder()
+        .build()?
+        .html()?
+        .contains("Chooses the **output** format"));
+
+    let termion = help(
+        builder()
+            .markup(true)
+            .style(Themed::new(Theme::dark()).depth(Depth::True))
+            .color(ColorChoice::Always)
+            .build()?,
+    );
+    assert!(termion.contains("\x1b[1moutput"));
+    assert!(termion.contains("\x1b[3mtext"));
+    assert!(termion.contains('•'));
+    Ok(())
+}
+
+#[test]
+fn links_are_clickable_on_supporting_terminals() -> Result {
+    assert_eq!(
+        Links::from_environment(environment(&[("TERM_PROGRAM", "WezTerm")])),
+        Links::Enabled
+    );
+    assert_eq!(
+        Links::from_environment(environment(&[("VTE_VERSION", "6003")])),
+        Links::Enabled
+    );
+    assert_eq!(
+        Links::from_environment(environment(&[("VTE_VERSION", "4200")])),
+        Links::Disabled
+    );
+    assert_eq!(
+        Links::from_environment(environment(&[
+            ("TERM", "xterm-kitty"),
+            ("FORCE_HYPERLINK", "0")
+        ])),
+        Links::Disabled
+    );
+    assert_eq!(
+        Links::from_environment(environment(&[("TERM", "xterm-256color")])),
+        Links::Disabled
+    );
+
+    let builder = || {
+        Parser::builder()
+            .name("tool")
+            .home("https://example.com")
+            .help("Read the guide at https://example.com/guide.")
+            .option::<bool, _>(|option| option.name("v").default(false))
+    };
+    let help = |parser: Parser<_>| help(&parser, ["--help"], [("", "")]);
+    let termion = |links| {
+        builder()
+            .style(Themed::new(Theme::dark()).depth(Depth::True).links(links))
+            .color(ColorChoice::Always)
+            .build()
+    };
+
+    let enabled = help(termion(Links::Enabled)?);
+    assert!(enabled.contains("\x1b]8;;https://example.com\x1b\\https://example.com\x1b]8;;\x1b\\"));
+    assert!(enabled.contains(
+        "\x1b]8;;https://example.com/guide\x1b\\https://example.com/guide\x1b]8;;\x1b\\."
+    ));
+    let disabled = help(termion(Links::Disabled)?);
+    assert!(!disabled.contains("\x1b]8"));
+    assert!(disabled.contains("https://example.com/guide."));
+    let plain = help(builder().style(scalp::style::Plain).build()?);
+    assert!(!plain.contains("\x1b]8"));
+    assert!(plain.contains("https://example.com/guide."));
+
+    (regex!("[a-z]{1,8}"), regex!("[1-9a-z]{1,8}")).check(COUNT, |(term, force)| {
+        let links =
+            Links::from_environment(environment(&[("TERM", term), ("FORCE_HYPERLINK", force)]));
+        prove!(links == Links::Enabled)
+    })?;
+    regex!("[a-z0-9]{1,12}(/[a-z0-9]{1,8}){0,2}").check(COUNT, |path| {
+        let url = format!("https://example.com/{path}");
+        let parser = |links| {
+            Parser::builder()
+                .name("tool")
+                .help(format!("Read {url} now."))
+                .style(Themed::new(Theme::dark()).depth(Depth::True).links(links))
+                .color(ColorChoice::Always)
+                .build()
+                .unwrap()
+        };
+        let enabled = self::help(&parser(Links::Enabled), ["--help"], [("COLUMNS", "200")]);
+        let disabled = self::help(&parser(Links::Disabled), ["--help"], [("COLUMNS", "200")]);
+        prove!(
+            enabled.contains(&format!("\x1b]8;;{url}\x1b\\{url}\x1b]8;;\x1b\\ now."))
+                && !disabled.contains("\x1b]8")
+                && disabled.contains(&format!("{url} now."))
+        )
+    })?;
+    Ok(())
+}
+
+#[test]
+fn secret_defaults_are_hidden_from_help() -> Result {
+    let parser = Parser::builder()
+        .name("tool")
+        .style(scalp::style::Plain)
+        .option::<String, _>(|option| {
+            option
+                .name("token")
+                .secret()
+                .environment("TOOL_TOKEN")
+                .default("hunter2")
+        })
+        .build()?;
+    let help = help(&parser, ["--help"], [("", "")]);
+    assert!(help.contains("secret"));
+    assert!(help.contains("$TOOL_TOKEN"));
+    assert!(!help.contains("hunter2"));
+    assert!(!parser.markdown()?.contains("hunter2"));
+    let description = parser.describe();
+    assert!(description.options[0].secret);
+    assert_eq!(description.options[0].default, None);
+    Ok(())
+}
@@ -1,10 +1,36 @@
 use checkito::*;
 use scalp::{Case, Error, Parser};
-use std::{error, result, str::FromStr};
+use std::{
+    error, fs,
+    path::PathBuf,
+    process, result,
+    str::FromStr,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 type Result = result::Result<(), Box<dyn error::Error>>;
 const COUNT: usize = 1000;
 
+struct Temporary(PathBuf);
+
+impl Temporary {
+    fn new() -> result::Result<Self, Box<dyn error::Error>> {
+        static INDEX: AtomicUsize = AtomicUsize::new(0);
+        let time = SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos();
+        let index = INDEX.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("scalp-{}-{time}-{index}", process::id()));
+        fs::create_dir(&path)?;
+        Ok(Self(path))
+    }
+}
+
+impl Drop for Temporary {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
 #[test]
 fn empty_parser_builds() -> Result {
     Parser::builder().build()?;
@@ -219,3 +245,302 @@ fn parses_enum_value() -> Result {
     );
     Ok(())
 }
+
+#[test]
+fn completes_names_and_values() -> Result {
+    let parser = Parser::builder()
+        .option::<bool, _>(|option| option.name("debug").default(false))
+        .verb(|verb| {
+            verb.name("checkout").option(|option| {
+                option
+                    .name("b")
+                    .name("branch")
+                    .parse::<String>()
+                    .complete_with(|prefix| {
+                        ["main", "master", "develop"]
+                            .into_iter()
+                            .filter(|branch| branch.starts_with(prefix))
+                            .map(String::from)
+                            .collect()
+                    })
+            })
+        })
+        .build()?;
+    assert_eq!(
+        parser.parse_with(["--de"], [("SCALP_COMPLETE", "1")]),
+        Err(Error::Complete(vec!["--debug".into()]))
+    );
+    assert_eq!(
+        parser.parse_with(["--debug", "ch"], [("SCALP_COMPLETE", "2")]),
+        Err(Error::Complete(vec!["checkout".into()]))
+    );
+    assert_eq!(
+        parser.parse_with(["checkout", "--b"], [("SCALP_COMPLETE", "2")]),
+        Err(Error::Complete(vec!["--branch".into()]))
+    );
+    assert_eq!(
+        parser.complete_with(["checkout", "--branch", "ma"], [("", "")], 3),
+        vec!["main", "master"]
+    );
+    assert_eq!(
+        parser.complete_with(["checkout", "--unknown", "-b"], [("", "")], 4),
+        vec!["main", "master", "develop"]
+    );
+    assert!(parser.complete_with(["--debug"], [("", "")], 0).is_empty());
+    Ok(())
+}
+
+#[test]
+fn unrecognized_arguments_suggest_transpositions_first() -> Result {
+    let builder = || {
+        Parser::builder()
+            .option::<bool, _>(|option| option.name("verbose").default(false))
+            .option::<bool, _>(|option| option.name("version-check").default(false))
+            .option::<bool, _>(|option| option.name("verb").default(false))
+    };
+    let suggestions = |parser: Parser<_>, argument: &'static str| match parser
+        .parse_with([argument], [("", "")])
+    {
+        Err(Error::UnrecognizedArgument(_, suggestions)) => suggestions
+            .into_iter()
+            .map(|(suggestion, _)| suggestion)
+            .collect::<Vec<_>>(),
+        result => panic!("expected an unrecognized argument, got {result:?}"),
+    };
+    assert_eq!(
+        suggestions(builder().build()?, "--verobse"),
+        ["--verbose", "--verb"]
+    );
+    assert_eq!(
+        suggestions(builder().build()?, "--verbs"),
+        ["--verb", "--verbose"]
+    );
+    assert!(suggestions(builder().suggestions(3, 0).build()?, "--verobse").is_empty());
+    assert_eq!(
+        suggestions(builder().suggestions(1, 5).build()?, "--version"),
+        ["--verb", "--verbose"]
+    );
+
+    let weighted = |weight: usize, argument: &'static str| {
+        let parser = Parser::builder()
+            .option::<bool, _>(|option| option.name("batch").default(false))
+            .option::<bool, _>(|option| option.name("catch").weight(weight).default(false))
+            .verb(|verb| verb.name("bake"))
+            .verb(|verb| verb.name("cake").weight(weight))
+            .build()?;
+        match parser.parse_with([argument], [("", "")]) {
+            Err(Error::UnrecognizedArgument(_, suggestions)) => Ok::<_, Error>(
+                suggestions
+                    .into_iter()
+                    .map(|(suggestion, _)| suggestion)
+                    .collect::<Vec<_>>(),
+            ),
+            result => panic!("expected an unrecognized argument, got {result:?}"),
+        }
+    };
+    assert_eq!(weighted(0, "--hatch")?, ["--batch", "--catch"]);
+    assert_eq!(weighted(5, "--hatch")?, ["--catch", "--batch"]);
+    assert_eq!(weighted(0, "lake")?, ["bake", "cake"]);
+    assert_eq!(weighted(5, "lake")?, ["cake", "bake"]);
+    Ok(())
+}
+
+#[test]
+fn misplaced_arguments_point_to_their_verb() -> Result {
+    let parser = Parser::builder()
+        .name("tool")
+        .option::<bool, _>(|option| option.name("debug").default(false))
+        .verb(|verb| {
+            verb.name("run")
+                .option::<bool, _>(|option| option.name("force").default(false))
+        })
+        .verb(|verb| {
+            verb.name("build")
+                .option::<bool, _>(|option| option.name("release").default(false))
+        })
+        .build()?;
+    let error = parser.parse_with(["--force"], [("", "")]).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "'--force' is an option of 'run'; did you mean 'tool run --force'?"
+    );
+    let error = parser
+        .parse_with(["build", "--force"], [("", "")])
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "'--force' is an option of 'run'; did you mean 'tool run --force'?"
+    );
+    let error = parser
+        .parse_with(["run", "--debug"], [("", "")])
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "'--debug' is an option of 'tool'; did you mean 'tool --debug'?"
+    );
+    assert!(matches!(
+        parser.parse_with(["--forse"], [("", "")]),
+        Err(Error::UnrecognizedArgument(..))
+    ));
+    Ok(())
+}
+
+#[test]
+fn missing_values_are_prompted_until_valid() -> Result {
+    let parser = Parser::builder()
+        .option::<u8, _>(|option| option.name("count").prompt("Enter count").require())
+        .option::<String, _>(|option| {
+            option
+                .name("level")
+                .valid("debug")
+                .valid("info")
+                .prompt("Select level")
+                .require()
+        })
+        .build()?;
+    let mut output = Vec::new();
+    let (count, level) = parser.prompt_with(
+        ["--level", "info"],
+        [("", "")],
+        "boba\n300\n7\n".as_bytes(),
+        &mut output,
+    )?;
+    assert_eq!(count, 7);
+    assert_eq!(level, "info");
+    let output = String::from_utf8(output)?;
+    assert_eq!(output.matches("Enter count: ").count(), 3);
+    assert!(!output.contains("Select level"));
+
+    let mut output = Vec::new();
+    let (count, level) = parser.prompt_with(
+        ["--count", "1"],
+        [("", "")],
+        "trace\n2\n".as_bytes(),
+        &mut output,
+    )?;
+    assert_eq!((count, level.as_str()), (1, "info"));
+    let output = String::from_utf8(output)?;
+    assert!(output.starts_with("Select level:\n  1) debug\n  2) info\n> "));
+    assert_eq!(output.matches("> ").count(), 2);
+
+    assert!(matches!(
+        parser.prompt_with(["--count", "1"], [("", "")], "".as_bytes(), Vec::new()),
+        Err(Error::MissingRequiredOption(..))
+    ));
+    assert!(matches!(
+        parser.parse_with(["--count", "1"], [("", "")]),
+        Err(Error::MissingRequiredOption(..))
+    ));
+    Ok(())
+}
+
+#[test]
+fn secret_values_are_redacted_from_errors() -> Result {
+    let parser = Parser::builder()
+        .option::<u32, _>(|option| {
+            option
+                .name("pin")
+                .secret()
+                .environment("TOOL_PIN")
+                .prompt("Enter pin")
+        })
+        .option::<String, _>(|option| option.name("token").secret().valid("[a-f0-9]+"))
+        .build()?;
+    let error = parser
+        .parse_with(["--pin", "hunter2"], [("", "")])
+        .unwrap_err();
+    assert!(matches!(error, Error::FailedToParseOptionValue(..)));
+    assert!(!error.to_string().contains("hunter2"));
+    assert!(error.to_string().contains("***"));
+    let error = parser
+        .parse_with(["--token", "hunter2"], [("", "")])
+        .unwrap_err();
+    assert!(matches!(error, Error::InvalidOptionValue(..)));
+    assert!(!error.to_string().contains("hunter2"));
+    let error = parser
+        .parse_with(["--token", "abc"], [("TOOL_PIN", "hunter2")])
+        .unwrap_err();
+    assert!(matches!(error, Error::FailedToParseEnvironmentVariable(..)));
+    assert!(!error.to_string().contains("hunter2"));
+
+    let mut output = Vec::new();
+    let (pin, _) = parser.prompt_with(
+        ["--token", "abc"],
+        [("", "")],
+        "hunter2\n1234\n".as_bytes(),
+        &mut output,
+    )?;
+    assert_eq!(pin, Some(1234));
+    let output = String::from_utf8(output)?;
+    assert!(!output.contains("hunter2"));
+    assert!(output.contains("***"));
+    Ok(())
+}
+
+#[test]
+fn file_options_read_their_value_from_files_or_stdin() -> Result {
+    let directory = Temporary::new()?;
+    let password = directory.0.join("password");
+    fs::write(&password, "hunter2\r\n\n")?;
+    let large = directory.0.join("large");
+    fs::write(&large, vec![b'a'; (1 << 20) + 1])?;
+    let missing = directory.0.join("missing");
+    let (password, large, missing) = (
+        password.display().to_string(),
+        large.display().to_string(),
+        missing.display().to_string(),
+    );
+
+    let owned = |arguments: &[&str]| -> Vec<String> {
+        arguments
+            .iter()
+            .map(|argument| argument.to_string())
+            .collect()
+    };
+    let parser = Parser::builder()
+        .option::<String, _>(|option| option.name("password-file").from_file())
+        .option::<String, _>(|option| option.name("token").file_or_value())
+        .build()?;
+    let values = parser.parse_with(
+        owned(&["--password-file", &password, "--token", "boba"]),
+        [("", "")],
+    )?;
+    assert_eq!(values, (Some("hunter2".into()), Some("boba".into())));
+    let values = parser.parse_with(owned(&["--token", &format!("@{password}")]), [("", "")])?;
+    assert_eq!(values, (None, Some("hunter2".into())));
+    let values = parser.prompt_with(
+        ["--password-file", "-"],
+        [("", "")],
+        "fett\n".as_bytes(),
+        Vec::new(),
+    )?;
+    assert_eq!(values, (Some("fett".into()), None));
+
+    let error = parser
+        .parse_with(owned(&["--password-file", &missing]), [("", "")])
+        .unwrap_err();
+    assert!(matches!(error, Error::FailedToReadOptionFile(..)));
+    assert!(error.to_string().contains(&missing));
+    assert!(error.to_string().contains("--password-file"));
+    let error = parser
+        .parse_with(owned(&["--token", &format!("@{large}")]), [("", "")])
+        .unwrap_err();
+    assert!(matches!(error, Error::OptionFileTooLarge(..)));
+
+    let parser = Parser::builder()
+        .option::<usize, _>(|option| option.name("count").from_file())
+        .option::<String, _>(|option| option.name("pin").from_file().limit(4))
+        .build()?;
+    let error = parser
+        .parse_with(owned(&["--count", &password]), [("", "")])
+        .unwrap_err();
+    assert!(matches!(error, Error::FailedToParseOptionFile(..)));
+    assert!(error.to_string().contains(&password));
+    assert!(error.to_string().contains("--count"));
+    assert!(!error.to_string().contains("hunter2"));
+    let error = parser
+        .parse_with(owned(&["--pin", &password]), [("", "")])
+        .unwrap_err();
+    assert!(matches!(error, Error::OptionFileTooLarge(_, 4, _)));
+    Ok(())
+}